@@ -0,0 +1,85 @@
+//! Timing helpers for benchmarking solutions.
+
+use std::time::{Duration, Instant};
+
+/// Summary of repeated timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Runs `f` `repetitions` times (at least once) and times each run.
+/// Returns the timings together with the result of the last run.
+pub fn measure<T>(repetitions: usize, mut f: impl FnMut() -> T) -> (Stats, T) {
+    let mut samples = vec![];
+    let mut result = None;
+    for _ in 0..repetitions.max(1) {
+        let start = Instant::now();
+        result = Some(f());
+        samples.push(start.elapsed());
+    }
+
+    let result = result.expect("ran at least once");
+    (Stats::from_samples(samples), result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+
+        // with an even number of samples, the upper of the two middle samples is the median
+        let stats = Stats::from_samples(millis(&[4, 1, 3, 2]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+
+        let stats = Stats::from_samples(millis(&[7]));
+        assert_eq!(
+            (stats.min, stats.median, stats.max),
+            (stats.max, stats.max, stats.max)
+        );
+    }
+
+    #[test]
+    fn test_measure_runs_at_least_once() {
+        let mut runs = 0;
+        let (_, result) = measure(0, || {
+            runs += 1;
+            runs
+        });
+        assert_eq!(result, 1);
+
+        let (_, result) = measure(3, || {
+            runs += 1;
+            runs
+        });
+        assert_eq!(result, 4);
+    }
+}
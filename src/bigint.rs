@@ -0,0 +1,507 @@
+//! A simple arbitrary precision integer, for answers that don't fit into (or might overflow)
+//! the primitive integer types.
+//!
+//! Nothing fancy: schoolbook multiplication and bitwise long division,
+//! which is plenty fast for the sizes that show up in puzzles.
+
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Signed integer of arbitrary size.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    /// Little endian base 2^32 digits without trailing zeros; empty for zero.
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn one() -> Self {
+        Self::from(1u32)
+    }
+
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        let negative = negative && !magnitude.is_empty();
+        Self {
+            negative,
+            magnitude,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::from_parts(false, self.magnitude.clone())
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut base = self.clone();
+        let mut ret = Self::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                ret *= &base;
+            }
+            base = &base * &base;
+            exponent >>= 1;
+        }
+        ret
+    }
+
+    /// Division truncating towards zero, like for the primitive types.
+    /// Panics if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &rhs.magnitude);
+        (
+            Self::from_parts(self.negative != rhs.negative, quotient),
+            Self::from_parts(self.negative, remainder),
+        )
+    }
+
+    /// The remainder that is never negative.
+    pub fn rem_euclid(&self, rhs: &Self) -> Self {
+        let remainder = self % rhs;
+        if remainder.is_negative() {
+            remainder + rhs.abs()
+        } else {
+            remainder
+        }
+    }
+
+    /// Greatest common divisor, always non-negative.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.abs(), other.abs());
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Least common multiple, always non-negative.
+    pub fn lcm(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        (self / &self.gcd(other) * other).abs()
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self.to_i128()?).ok()
+    }
+
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.magnitude.len() > 4 {
+            return None;
+        }
+        let magnitude = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0u128, |acc, digit| (acc << 32) | *digit as u128);
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+}
+
+fn compare_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut ret = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+    for i in 0..a.len().max(b.len()) {
+        let sum = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        ret.push(sum as u32);
+        carry = sum >> 32;
+    }
+    ret.push(carry as u32);
+    ret
+}
+
+/// `a - b`; `a` must not be smaller than `b`.
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut ret = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, x) in a.iter().enumerate() {
+        let mut difference = *x as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if difference < 0 {
+            difference += 1 << 32;
+            borrow = 1;
+        }
+        ret.push(difference as u32);
+    }
+    debug_assert_eq!(borrow, 0, "subtracted a larger magnitude");
+    ret
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut ret = vec![0u32; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, y) in b.iter().enumerate() {
+            let product = *x as u64 * *y as u64 + ret[i + j] as u64 + carry;
+            ret[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        ret[i + b.len()] = carry as u32;
+    }
+    ret
+}
+
+fn div_rem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare_magnitude(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+
+    // long division, one bit at a time
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder: Vec<u32> = vec![];
+    for i in (0..a.len() * 32).rev() {
+        // remainder = remainder * 2 + next bit
+        let mut carry = (a[i / 32] >> (i % 32)) & 1;
+        for digit in remainder.iter_mut() {
+            let next_carry = *digit >> 31;
+            *digit = (*digit << 1) | carry;
+            carry = next_carry;
+        }
+        if carry != 0 {
+            remainder.push(carry);
+        }
+
+        if compare_magnitude(&remainder, b) != Ordering::Less {
+            remainder = sub_magnitude(&remainder, b);
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+            quotient[i / 32] |= 1 << (i % 32);
+        }
+    }
+
+    (quotient, remainder)
+}
+
+/// Divides in place by a small divisor, returning the remainder.
+fn div_rem_small(a: &mut Vec<u32>, divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+    for digit in a.iter_mut().rev() {
+        let current = (remainder << 32) | *digit as u64;
+        *digit = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+    remainder as u32
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        Self::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(
+                self.negative,
+                add_magnitude(&self.magnitude, &rhs.magnitude),
+            );
+        }
+        match compare_magnitude(&self.magnitude, &rhs.magnitude) {
+            Ordering::Less => {
+                BigInt::from_parts(rhs.negative, sub_magnitude(&rhs.magnitude, &self.magnitude))
+            }
+            _ => BigInt::from_parts(
+                self.negative,
+                sub_magnitude(&self.magnitude, &rhs.magnitude),
+            ),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            mul_magnitude(&self.magnitude, &rhs.magnitude),
+        )
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+/// Implements the owned variants of a binary operator in terms of the one on references.
+macro_rules! forward_binary_op {
+    ($($trait:ident $method:ident),*) => {
+        $(
+            impl $trait for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: BigInt) -> BigInt {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $trait<&BigInt> for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: &BigInt) -> BigInt {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $trait<BigInt> for &BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: BigInt) -> BigInt {
+                    self.$method(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+forward_binary_op!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+impl AddAssign<&BigInt> for BigInt {
+    fn add_assign(&mut self, rhs: &BigInt) {
+        *self = &*self + rhs;
+    }
+}
+
+impl SubAssign<&BigInt> for BigInt {
+    fn sub_assign(&mut self, rhs: &BigInt) {
+        *self = &*self - rhs;
+    }
+}
+
+impl MulAssign<&BigInt> for BigInt {
+    fn mul_assign(&mut self, rhs: &BigInt) {
+        *self = &*self * rhs;
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(value: u128) -> Self {
+        let magnitude = (0..4).map(|i| (value >> (32 * i)) as u32).collect();
+        Self::from_parts(false, magnitude)
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let magnitude = BigInt::from(value.unsigned_abs()).magnitude;
+        Self::from_parts(value < 0, magnitude)
+    }
+}
+
+/// Conversions from the primitive integer types, which all fit into `i128` or `u128`.
+macro_rules! from_primitive {
+    ($($via:ty: $($t:ty),*;)*) => {
+        $($(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    Self::from(value as $via)
+                }
+            }
+        )*)*
+    };
+}
+
+from_primitive! {
+    u128: u8, u16, u32, u64, usize;
+    i128: i8, i16, i32, i64, isize;
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("invalid integer `{s}`"));
+        }
+
+        let ten = BigInt::from(10u32);
+        let mut ret = BigInt::zero();
+        for digit in digits.bytes() {
+            ret = ret * &ten + BigInt::from(digit - b'0');
+        }
+        Ok(if negative { -ret } else { ret })
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // split off 9 decimal digits at a time
+        const CHUNK: u32 = 1_000_000_000;
+        let mut magnitude = self.magnitude.clone();
+        let mut chunks = vec![];
+        while !magnitude.is_empty() {
+            chunks.push(div_rem_small(&mut magnitude, CHUNK));
+        }
+
+        let mut digits = chunks.last().map_or("0".to_owned(), u32::to_string);
+        for chunk in chunks.iter().rev().skip(1) {
+            digits += &format!("{chunk:09}");
+        }
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl Debug for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const VALUES: &[i128] = &[
+        0,
+        1,
+        -1,
+        7,
+        -13,
+        u32::MAX as i128,
+        u32::MAX as i128 + 1,
+        -(u64::MAX as i128),
+        123_456_789_012_345_678,
+        -987_654_321_987_654_321,
+    ];
+
+    #[test]
+    fn test_matches_i128() {
+        for &a in VALUES {
+            for &b in VALUES {
+                let (x, y) = (BigInt::from(a), BigInt::from(b));
+                assert_eq!((&x + &y).to_i128(), Some(a + b), "{a} + {b}");
+                assert_eq!((&x - &y).to_i128(), Some(a - b), "{a} - {b}");
+                assert_eq!(x.cmp(&y), a.cmp(&b), "{a} cmp {b}");
+                if let Some(product) = a.checked_mul(b) {
+                    assert_eq!((&x * &y).to_i128(), Some(product), "{a} * {b}");
+                }
+                if b != 0 {
+                    assert_eq!((&x / &y).to_i128(), Some(a / b), "{a} / {b}");
+                    assert_eq!((&x % &y).to_i128(), Some(a % b), "{a} % {b}");
+                    assert_eq!(x.rem_euclid(&y).to_i128(), Some(a.rem_euclid(b)));
+                }
+                assert_eq!(x.to_string(), a.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_values() {
+        let factorial = (1..=30u32).fold(BigInt::one(), |acc, n| acc * BigInt::from(n));
+        assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+        assert_eq!(factorial.to_i128(), Some(265252859812191058636308480000000));
+        assert_eq!(factorial.pow(2).to_i128(), None);
+        assert_eq!(
+            "265252859812191058636308480000000".parse::<BigInt>(),
+            Ok(factorial.clone())
+        );
+
+        let squared = factorial.pow(2);
+        assert_eq!(&squared / &factorial, factorial);
+        assert!((&squared % &factorial).is_zero());
+        assert_eq!(squared.gcd(&factorial), factorial);
+    }
+
+    #[test]
+    fn test_lcm() {
+        let a = BigInt::from(u64::MAX);
+        let b = BigInt::from(u64::MAX - 1);
+        // consecutive numbers are coprime
+        assert_eq!(
+            a.lcm(&b),
+            BigInt::from(u64::MAX as u128 * (u64::MAX - 1) as u128)
+        );
+        assert_eq!(BigInt::from(-4).lcm(&BigInt::from(6)), BigInt::from(12));
+    }
+}
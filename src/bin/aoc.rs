@@ -0,0 +1,537 @@
+use std::{
+    any::Any,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
+};
+
+use aoc2023::{
+    bench, days, diagnostics,
+    input::{self, Source},
+    solution::{DynSolution, Part, Style},
+};
+
+const USAGE: &str = "usage:
+    aoc run --day <N> [--part <1|2>] [--input <path>] [--format <text|json>]
+    aoc render --day <N> [--part <1|2>] [--input <path>] [--color]
+    aoc bench [--day <N>] [--input <path>] [--repetitions <N>] [--summary <path>]
+    aoc new --day <N>
+
+The input defaults to `input/<N>.txt`; use `--input -` to read from stdin.
+`render` writes only the visualizations to stdout, so with `--part` it is a single document,
+e.g. `aoc render --day 8 --part 1 | dot -Tsvg`.";
+
+const DEFAULT_REPETITIONS: u32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// Answers on stdout, diagnostics on stderr.
+    Text,
+    /// One JSON object per part on stdout, including the diagnostics.
+    Json,
+}
+
+#[derive(Debug)]
+struct RunArgs {
+    day: u32,
+    // `None` runs both parts
+    part: Option<Part>,
+    input: Option<String>,
+    format: Format,
+    style: Style,
+}
+
+impl RunArgs {
+    /// Parses the arguments of `aoc <command>`, where `command` is `run` or `render`:
+    /// `--format` only applies to `run` and `--color` only to `render`.
+    fn parse(command: &str, mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut part = None;
+        let mut input = None;
+        let mut format = Format::Text;
+        let mut style = Style::Plain;
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for `{arg}`"));
+            match arg.as_str() {
+                "--day" | "-d" => day = Some(parse_number(&value()?, "day")?),
+                "--format" | "-f" if command == "run" => {
+                    format = match value()?.as_str() {
+                        "text" => Format::Text,
+                        "json" => Format::Json,
+                        f => return Err(format!("format must be `text` or `json`, got `{f}`")),
+                    }
+                }
+                "--part" | "-p" => {
+                    part = Some(match parse_number(&value()?, "part")? {
+                        1 => Part::One,
+                        2 => Part::Two,
+                        p => return Err(format!("part must be 1 or 2, got {p}")),
+                    })
+                }
+                "--input" | "-i" => input = Some(value()?),
+                "--color" if command == "render" => style = Style::Ansi,
+                _ => return Err(format!("unknown argument `{arg}` for `aoc {command}`")),
+            }
+        }
+
+        Ok(Self {
+            day: day.ok_or("missing `--day`")?,
+            part,
+            input,
+            format,
+            style,
+        })
+    }
+}
+
+fn parse_number(s: &str, what: &str) -> Result<u32, String> {
+    s.parse().map_err(|_| format!("invalid {what} `{s}`"))
+}
+
+impl RunArgs {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        }
+    }
+
+    /// Looks up the solution for the selected day and parses its input with it.
+    fn load(&self) -> Result<(&'static dyn DynSolution, Box<dyn Any>), String> {
+        let solution = get_solution(self.day)?;
+        let (source, input) = read_input(self.day, self.input.as_deref())?;
+
+        let input = solution
+            .parse(&input)
+            .map_err(|e| format!("invalid input from `{source}`: {e}"))?;
+        Ok((solution, input))
+    }
+}
+
+#[derive(Debug)]
+struct BenchArgs {
+    // `None` benchmarks every day that has an input file
+    day: Option<u32>,
+    input: Option<String>,
+    repetitions: u32,
+    summary: Option<String>,
+}
+
+impl BenchArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut day = None;
+        let mut input = None;
+        let mut repetitions = DEFAULT_REPETITIONS;
+        let mut summary = None;
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for `{arg}`"));
+            match arg.as_str() {
+                "--day" | "-d" => day = Some(parse_number(&value()?, "day")?),
+                "--input" | "-i" => input = Some(value()?),
+                "--repetitions" | "-n" => {
+                    repetitions = parse_number(&value()?, "number of repetitions")?;
+                    if repetitions == 0 {
+                        return Err("need at least one repetition".to_owned());
+                    }
+                }
+                "--summary" => summary = Some(value()?),
+                _ => return Err(format!("unknown argument `{arg}`")),
+            }
+        }
+
+        if input.is_some() && day.is_none() {
+            return Err("`--input` needs a `--day`".to_owned());
+        }
+
+        Ok(Self {
+            day,
+            input,
+            repetitions,
+            summary,
+        })
+    }
+}
+
+fn get_solution(day: u32) -> Result<&'static dyn DynSolution, String> {
+    days::get(day).ok_or(format!("no solution for day {day}"))
+}
+
+/// Reads the input for `day`, returning where it was read from and its contents.
+fn read_input(day: u32, path: Option<&str>) -> Result<(Source, String), String> {
+    let source = Source::resolve(day, path);
+    let input = source
+        .read()
+        .map_err(|e| format!("can't read input from `{source}`: {e}"))?;
+    Ok((source, input))
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    if args.format == Format::Json {
+        return run_json(args);
+    }
+
+    let (solution, input) = args.load()?;
+    for part in args.parts() {
+        let answer = solution.solve(input.as_ref(), part);
+        println!("day {} part {part}: {answer}", args.day);
+    }
+
+    Ok(())
+}
+
+/// Like [`run`], but prints a JSON object per part.
+/// Diagnostics logged while parsing are included for every part.
+fn run_json(args: RunArgs) -> Result<(), String> {
+    let (loaded, parse_diagnostics) = diagnostics::capture(|| args.load());
+    let (solution, input) = loaded?;
+
+    for part in args.parts() {
+        let ((answer, elapsed), part_diagnostics) = diagnostics::capture(|| {
+            let start = Instant::now();
+            let answer = solution.solve(input.as_ref(), part);
+            (answer, start.elapsed())
+        });
+
+        let diagnostics = parse_diagnostics
+            .iter()
+            .chain(&part_diagnostics)
+            .map(|d| json_string(d))
+            .collect::<Vec<_>>()
+            .join(",");
+        println!(
+            r#"{{"day":{},"part":{part},"answer":{},"elapsed":{},"diagnostics":[{diagnostics}]}}"#,
+            args.day,
+            json_string(&answer),
+            elapsed.as_secs_f64(),
+        );
+    }
+
+    Ok(())
+}
+
+/// Quotes and escapes `s` as a JSON string.
+fn json_string(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len() + 2);
+    ret.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            '\r' => ret.push_str("\\r"),
+            '\t' => ret.push_str("\\t"),
+            c if c.is_control() => ret.push_str(&format!("\\u{:04x}", c as u32)),
+            c => ret.push(c),
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+fn render(args: RunArgs) -> Result<(), String> {
+    let (solution, input) = args.load()?;
+    for part in args.parts() {
+        let rendered = solution
+            .render(input.as_ref(), part, args.style)
+            .ok_or(format!("day {} part {part} has no visualization", args.day))?;
+        // the header goes to stderr, so stdout can be fed to other tools, e.g. Graphviz for day 8
+        eprintln!("day {} part {part}:", args.day);
+        println!("{rendered}");
+    }
+
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => days::REGISTRY
+            .iter()
+            .map(|(day, _)| *day)
+            .filter(|day| {
+                let exists = input::default_path(*day).exists();
+                if !exists {
+                    eprintln!("skipping day {day}: no input file");
+                }
+                exists
+            })
+            .collect(),
+    };
+
+    if days.is_empty() {
+        return Err("no input files to benchmark".to_owned());
+    }
+
+    let repetitions = args.repetitions as usize;
+    let mut results = vec![];
+    for day in days {
+        let solution = get_solution(day)?;
+        let (source, input) = read_input(day, args.input.as_deref())?;
+
+        // diagnostics would be logged on every repetition, so they're dropped
+        let ((parse_stats, parsed), _) =
+            diagnostics::capture(|| bench::measure(repetitions, || solution.parse(&input)));
+        let parsed = parsed.map_err(|e| format!("invalid input from `{source}`: {e}"))?;
+        results.push((day, "parse", parse_stats));
+
+        for (part, stage) in [(Part::One, "part1"), (Part::Two, "part2")] {
+            let ((stats, _), _) = diagnostics::capture(|| {
+                bench::measure(repetitions, || solution.solve(parsed.as_ref(), part))
+            });
+            results.push((day, stage, stats));
+        }
+    }
+
+    println!("{repetitions} repetitions each");
+    for (day, stage, stats) in &results {
+        println!(
+            "day {day:>2} {stage:<5}  min {:>12?}  median {:>12?}  max {:>12?}",
+            stats.min, stats.median, stats.max
+        );
+    }
+
+    if let Some(summary_file) = args.summary {
+        // tab separated, one line per day and stage, so it can be diffed between commits
+        let mut summary = String::from("day\tstage\trepetitions\tmin_ns\tmedian_ns\tmax_ns\n");
+        for (day, stage, stats) in &results {
+            summary += &format!(
+                "{day}\t{stage}\t{repetitions}\t{}\t{}\t{}\n",
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos()
+            );
+        }
+        std::fs::write(&summary_file, summary)
+            .map_err(|e| format!("can't write summary file `{summary_file}`: {e}"))?;
+    }
+
+    Ok(())
+}
+
+/// Module template for a new day; `{{day}}` is replaced by the zero padded day.
+const DAY_TEMPLATE: &str = include_str!("../../templates/day.rs");
+
+fn parse_new_args(mut args: impl Iterator<Item = String>) -> Result<u32, String> {
+    let mut day = None;
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for `{arg}`"));
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number(&value()?, "day")?),
+            _ => return Err(format!("unknown argument `{arg}`")),
+        }
+    }
+    Ok(day.ok_or("missing `--day`")?)
+}
+
+fn new_day(day: u32) -> Result<(), String> {
+    if days::get(day).is_some() {
+        return Err(format!("day {day} already exists"));
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for file in scaffold_day(root, day)? {
+        println!("created {}", file.display());
+    }
+    println!(
+        "fill in the example input in tests/examples/day{day:02}.txt and replace the `?` answers in tests/examples/answers.txt"
+    );
+    Ok(())
+}
+
+/// Generates `src/days/dayNN.rs` from the template and registers it in `src/days.rs` under `root`.
+/// Also adds an empty example file, with pending `?` answers so the golden tests still pass.
+/// Returns the created files.
+fn scaffold_day(root: &Path, day: u32) -> Result<Vec<PathBuf>, String> {
+    let padded = format!("{day:02}");
+    let module_file = root.join(format!("src/days/day{padded}.rs"));
+    let days_file = root.join("src/days.rs");
+    let example_file = root.join(format!("tests/examples/day{padded}.txt"));
+    let answers_file = root.join("tests/examples/answers.txt");
+
+    let read = |path: &Path| {
+        std::fs::read_to_string(path).map_err(|e| format!("can't read `{}`: {e}", path.display()))
+    };
+    let write = |path: &Path, contents: &str| {
+        std::fs::write(path, contents).map_err(|e| format!("can't write `{}`: {e}", path.display()))
+    };
+
+    let days_source = register_day(&read(&days_file)?, day)?;
+    let mut answers = read(&answers_file)?;
+
+    for file in [&module_file, &example_file] {
+        if file.exists() {
+            return Err(format!("`{}` already exists", file.display()));
+        }
+    }
+
+    if !answers.is_empty() && !answers.ends_with('\n') {
+        answers.push('\n');
+    }
+    answers.push_str(&format!("day{padded}.txt 1 ?\nday{padded}.txt 2 ?\n"));
+
+    write(&module_file, &DAY_TEMPLATE.replace("{{day}}", &padded))?;
+    write(&example_file, "")?;
+    write(&days_file, &days_source)?;
+    write(&answers_file, &answers)?;
+
+    Ok(vec![module_file, example_file])
+}
+
+/// Adds the module declaration and registry entry for `day` to the source of `src/days.rs`,
+/// keeping both sorted by day.
+fn register_day(source: &str, day: u32) -> Result<String, String> {
+    let padded = format!("{day:02}");
+    let mut lines = source.lines().map(str::to_owned).collect::<Vec<_>>();
+
+    // existing entries look like `pub mod day07;` and `    (7, &day07::Day07),`
+    insert_sorted(&mut lines, day, format!("pub mod day{padded};"), |line| {
+        line.strip_prefix("pub mod day")?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    })?;
+    insert_sorted(
+        &mut lines,
+        day,
+        format!("    ({day}, &day{padded}::Day{padded}),"),
+        |line| {
+            line.trim()
+                .strip_prefix('(')?
+                .split_once(',')?
+                .0
+                .parse()
+                .ok()
+        },
+    )?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `new_line` among the lines for which `day_of` returns a day, before the first one with a larger day.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    day: u32,
+    new_line: String,
+    day_of: impl Fn(&str) -> Option<u32>,
+) -> Result<(), String> {
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of(line).map(|d| (i, d)))
+        .collect::<Vec<_>>();
+    let Some(&(last, _)) = entries.last() else {
+        return Err(format!("can't find where to insert `{}`", new_line.trim()));
+    };
+
+    let position = entries
+        .iter()
+        .find(|(_, d)| *d > day)
+        .map_or(last + 1, |(i, _)| *i);
+    lines.insert(position, new_line);
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+
+    let result = match args.next().as_deref() {
+        Some("run") => RunArgs::parse("run", args).and_then(run),
+        Some("render") => RunArgs::parse("render", args).and_then(render),
+        Some("bench") => BenchArgs::parse(args).and_then(bench),
+        Some("new") => parse_new_args(args).and_then(new_day),
+        Some(command) => Err(format!("unknown command `{command}`\n{USAGE}")),
+        None => Err(USAGE.to_owned()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("plain"), r#""plain""#);
+        assert_eq!(json_string(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(json_string(r"C:\input"), r#""C:\\input""#);
+        assert_eq!(json_string("a\nb\r\tc"), r#""a\nb\r\tc""#);
+        assert_eq!(json_string("\u{0}\u{1b}[0m"), r#""\u0000\u001b[0m""#);
+        assert_eq!(json_string("╔═╗ ✓"), "\"╔═╗ ✓\"");
+    }
+
+    #[test]
+    fn test_run_args_per_command() {
+        let parse = |command, args: &[&str]| {
+            RunArgs::parse(command, args.iter().map(|arg| arg.to_string()))
+        };
+
+        let run = parse("run", &["-d", "3", "--format", "json"]).unwrap();
+        assert_eq!(
+            (run.day, run.format, run.style),
+            (3, Format::Json, Style::Plain)
+        );
+        let render = parse("render", &["--color", "-d", "3"]).unwrap();
+        assert_eq!((render.format, render.style), (Format::Text, Style::Ansi));
+
+        assert_eq!(
+            parse("run", &["-d", "3", "--color"]).unwrap_err(),
+            "unknown argument `--color` for `aoc run`"
+        );
+        assert_eq!(
+            parse("render", &["-d", "3", "-f", "json"]).unwrap_err(),
+            "unknown argument `-f` for `aoc render`"
+        );
+    }
+
+    #[test]
+    fn test_register_day() {
+        let source = "pub mod day01;\npub mod day03;\n\nstatic REGISTRY = &[\n    (1, &day01::Day01),\n    (3, &day03::Day03),\n];\n";
+        let expected = "pub mod day01;\npub mod day02;\npub mod day03;\n\nstatic REGISTRY = &[\n    (1, &day01::Day01),\n    (2, &day02::Day02),\n    (3, &day03::Day03),\n];\n";
+        assert_eq!(register_day(source, 2).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_scaffold_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("src/days")).unwrap();
+        std::fs::create_dir_all(root.join("tests/examples")).unwrap();
+        std::fs::write(
+            root.join("src/days.rs"),
+            "pub mod day01;\n\nstatic REGISTRY = &[\n    (1, &day01::Day01),\n];\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("tests/examples/answers.txt"), "day01.txt 1 3").unwrap();
+
+        let created = scaffold_day(&root, 2).unwrap();
+        let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+
+        assert_eq!(
+            created,
+            [
+                root.join("src/days/day02.rs"),
+                root.join("tests/examples/day02.txt")
+            ]
+        );
+        assert!(read("src/days/day02.rs").contains("pub struct Day02;"));
+        assert!(read("src/days.rs").contains("pub mod day02;"));
+        assert_eq!(read("tests/examples/day02.txt"), "");
+        assert_eq!(
+            read("tests/examples/answers.txt"),
+            "day01.txt 1 3\nday02.txt 1 ?\nday02.txt 2 ?\n"
+        );
+
+        // nothing is overwritten
+        assert!(scaffold_day(&root, 2).is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,117 @@
+//! Chinese remainder theorem for systems of congruences whose moduli don't need to be coprime.
+
+use crate::bigint::BigInt;
+
+/// Returns `(g, x, y)` with `a * x + b * y = g = gcd(a, b)`.
+pub fn extended_gcd(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+    let (mut old_r, mut r) = (a.clone(), b.clone());
+    let (mut old_x, mut x) = (BigInt::one(), BigInt::zero());
+    let (mut old_y, mut y) = (BigInt::zero(), BigInt::one());
+
+    while !r.is_zero() {
+        let quotient = &old_r / &r;
+        (old_r, r) = (r.clone(), &old_r - &quotient * &r);
+        (old_x, x) = (x.clone(), &old_x - &quotient * &x);
+        (old_y, y) = (y.clone(), &old_y - &quotient * &y);
+    }
+
+    if old_r.is_negative() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// A congruence `x ≡ remainder (mod modulus)`, with a positive modulus.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Congruence {
+    pub remainder: BigInt,
+    pub modulus: BigInt,
+}
+
+impl Congruence {
+    /// Normalizes the remainder into `0..modulus`. Panics if the modulus isn't positive.
+    pub fn new(remainder: BigInt, modulus: BigInt) -> Self {
+        assert!(
+            modulus > BigInt::zero(),
+            "modulus has to be positive, got {modulus}"
+        );
+        Self {
+            remainder: remainder.rem_euclid(&modulus),
+            modulus,
+        }
+    }
+
+    /// The congruence satisfied by exactly the numbers that satisfy both `self` and `other`,
+    /// or `None` if there are no such numbers.
+    pub fn combine(&self, other: &Congruence) -> Option<Congruence> {
+        let (g, p, _) = extended_gcd(&self.modulus, &other.modulus);
+        let difference = &other.remainder - &self.remainder;
+        if !(&difference % &g).is_zero() {
+            return None;
+        }
+
+        // self.remainder + self.modulus * k ≡ other.remainder (mod other.modulus)
+        // <=> (self.modulus / g) * k ≡ difference / g (mod other.modulus / g),
+        // and p is the inverse of self.modulus / g modulo other.modulus / g.
+        let reduced_modulus = &other.modulus / &g;
+        let k = (&difference / &g * p).rem_euclid(&reduced_modulus);
+        let modulus = &self.modulus * &reduced_modulus;
+        Some(Congruence::new(
+            &self.remainder + &self.modulus * k,
+            modulus,
+        ))
+    }
+
+    /// The smallest number that is at least `min` and satisfies the congruence.
+    pub fn smallest_at_least(&self, min: &BigInt) -> BigInt {
+        let offset = (min - &self.remainder).rem_euclid(&self.modulus);
+        if offset.is_zero() {
+            min.clone()
+        } else {
+            min + &(&self.modulus - &offset)
+        }
+    }
+}
+
+/// Solves a system of congruences; `None` if it has no solution.
+/// An empty system is satisfied by every number.
+pub fn solve(congruences: &[Congruence]) -> Option<Congruence> {
+    congruences
+        .iter()
+        .try_fold(Congruence::new(BigInt::zero(), BigInt::one()), |acc, c| {
+            acc.combine(c)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn congruence(remainder: i64, modulus: i64) -> Congruence {
+        Congruence::new(BigInt::from(remainder), BigInt::from(modulus))
+    }
+
+    #[test]
+    fn test_coprime() {
+        let solution = solve(&[congruence(2, 3), congruence(3, 5), congruence(2, 7)]);
+        assert_eq!(solution, Some(congruence(23, 105)));
+    }
+
+    #[test]
+    fn test_not_coprime() {
+        assert_eq!(
+            solve(&[congruence(2, 4), congruence(4, 6)]),
+            Some(congruence(10, 12))
+        );
+        assert_eq!(solve(&[congruence(1, 4), congruence(2, 6)]), None);
+    }
+
+    #[test]
+    fn test_smallest_at_least() {
+        let c = congruence(3, 10);
+        assert_eq!(c.smallest_at_least(&BigInt::from(0)), BigInt::from(3));
+        assert_eq!(c.smallest_at_least(&BigInt::from(3)), BigInt::from(3));
+        assert_eq!(c.smallest_at_least(&BigInt::from(4)), BigInt::from(13));
+    }
+}
@@ -0,0 +1,183 @@
+//! Cycle detection for sequences of states where every state only depends on the previous one,
+//! e.g. to find out what a simulation looks like after a huge number of steps.
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// The shape of a sequence that eventually repeats:
+/// after the first `start` states, the same `length` states repeat forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest step that has the same state as step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// All states of a sequence up to the point where it starts repeating.
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    states: Vec<S>,
+    cycle: Cycle,
+}
+
+impl<S> History<S> {
+    pub fn cycle(&self) -> Cycle {
+        self.cycle
+    }
+
+    /// The distinct states, starting with the initial one.
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    /// The state after `n` steps.
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.cycle.reduce(n)]
+    }
+}
+
+/// Finds the cycle by remembering every state in a hash map.
+/// Fast, but keeps all states before the first repetition in memory.
+pub fn detect<S>(initial: S, mut step: impl FnMut(&S) -> S) -> History<S>
+where
+    S: Hash + Eq + Clone,
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+
+    let mut current = initial;
+    loop {
+        match seen.entry(current.clone()) {
+            Entry::Occupied(e) => {
+                let start = *e.get();
+                let cycle = Cycle {
+                    start,
+                    length: states.len() - start,
+                };
+                return History { states, cycle };
+            }
+            Entry::Vacant(e) => {
+                e.insert(states.len());
+            }
+        }
+        let next = step(&current);
+        states.push(current);
+        current = next;
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which only keeps two states in memory at a time and
+/// doesn't need them to be hashable. In exchange, states are computed more than once.
+pub fn brent<S>(initial: &S, mut step: impl FnMut(&S) -> S) -> Cycle
+where
+    S: Eq + Clone,
+{
+    // find the cycle length by moving the hare ahead in powers of two
+    // until it meets the tortoise waiting at the last power of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // with the hare `length` steps ahead, both meet at the start of the cycle.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The state after `n` steps, found with [`brent`]; `n` can be far larger than what could be simulated.
+pub fn state_at<S>(initial: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S
+where
+    S: Eq + Clone,
+{
+    let cycle = brent(initial, &mut step);
+
+    let mut state = initial.clone();
+    for _ in 0..cycle.reduce(n) {
+        state = step(&state);
+    }
+    state
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 6, 2, 3, ...
+    fn step(n: &u32) -> u32 {
+        if *n == 6 {
+            2
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn test_detect() {
+        let history = detect(0, step);
+        assert_eq!(
+            history.cycle(),
+            Cycle {
+                start: 2,
+                length: 5
+            }
+        );
+        assert_eq!(history.states(), &[0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(*history.state_at(1), 1);
+        assert_eq!(*history.state_at(7), 2);
+        assert_eq!(
+            *history.state_at(1_000_000_000),
+            2 + (1_000_000_000 - 2) % 5
+        );
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(
+            brent(&0, step),
+            Cycle {
+                start: 2,
+                length: 5
+            }
+        );
+        assert_eq!(
+            brent(&3, step),
+            Cycle {
+                start: 0,
+                length: 5
+            }
+        );
+        assert_eq!(state_at(&0, step, 12), 2);
+    }
+}
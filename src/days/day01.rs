@@ -1,34 +1,38 @@
 use std::collections::HashMap;
 
-fn get_first_maybe_encoded_digit(text: &str, map: &HashMap<String, String>) -> String {
-    let first = text.chars().enumerate().find(|(_, c)| c.is_numeric());
+use crate::{
+    error::{self, ParseError},
+    solution::Solution,
+};
+
+fn get_first_maybe_encoded_digit(text: &str, map: &HashMap<String, String>) -> Option<String> {
+    // byte offsets, so slicing stays on char boundaries for non-ASCII lines
+    let first = text.char_indices().find(|(_, c)| c.is_ascii_digit());
 
     if let Some((first_idx, first)) = first {
         if let Some(value) = find_first_number(&text[0..first_idx], map) {
-            value
+            Some(value)
         } else {
-            first.to_string()
+            Some(first.to_string())
         }
-    } else if let Some(value) = find_first_number(text, map) {
-        value
     } else {
-        println!("nothing at all in line {text}");
-        unreachable!();
+        find_first_number(text, map)
     }
 }
 
+/// Returns `None` if there is no digit in the line at all.
 fn process_line(
     text: &str,
     map: &HashMap<String, String>,
     rev_map: &HashMap<String, String>,
-) -> u32 {
-    let mut first = get_first_maybe_encoded_digit(text, map);
+) -> Option<u32> {
+    let mut first = get_first_maybe_encoded_digit(text, map)?;
 
     let rev_line = text.chars().rev().collect::<String>();
-    let last = get_first_maybe_encoded_digit(&rev_line, rev_map);
+    let last = get_first_maybe_encoded_digit(&rev_line, rev_map)?;
     first += &last;
 
-    first.parse().unwrap()
+    first.parse().ok()
 }
 
 fn find_first_number(text: &str, map: &HashMap<String, String>) -> Option<String> {
@@ -39,8 +43,8 @@ fn find_first_number(text: &str, map: &HashMap<String, String>) -> Option<String
         if text.len() < k.len() {
             continue;
         }
-        for i in 0..text.len() - 1 {
-            if text[i..].starts_with(k) {
+        for (i, _) in text.char_indices() {
+            if text[i..].starts_with(k.as_str()) {
                 // potential hit
                 if i < first_start_idx {
                     first_start_idx = i;
@@ -80,15 +84,43 @@ fn reverse_string_map(original_map: &HashMap<String, String>) -> HashMap<String,
     map
 }
 
-fn main() {
-    let map = get_spelled_number_mapping();
-    let rev_map = reverse_string_map(&map);
-    let input = std::fs::read_to_string("input/1.txt").unwrap();
-    //let input = std::fs::read_to_string("input/one_part_2_training.txt").unwrap();
-    let total: u32 = input
-        .split('\n')
-        .map(|l| process_line(l, &map, &rev_map))
-        .sum();
-
-    println!("{total}");
+fn calibration_sum(lines: &[String], map: &HashMap<String, String>) -> u32 {
+    let rev_map = reverse_string_map(map);
+    // Lines with only spelled out digits have no value without the spelled number mapping.
+    lines
+        .iter()
+        .filter_map(|l| process_line(l, map, &rev_map))
+        .try_fold(0u32, u32::checked_add)
+        .expect("calibration sum overflows u32")
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = get_spelled_number_mapping();
+        let rev_map = reverse_string_map(&map);
+
+        error::lines(input)
+            .map(|line| {
+                if process_line(line.text(), &map, &rev_map).is_none() {
+                    return Err(line.error("no digit in line"));
+                }
+                Ok(line.text().to_owned())
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        // part 1 only considers actual digits
+        calibration_sum(input, &HashMap::new())
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        calibration_sum(input, &get_spelled_number_mapping())
+    }
 }
@@ -0,0 +1,188 @@
+use crate::{
+    diag,
+    error::{self, Line, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    rounds: Vec<Round>,
+}
+
+impl Game {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    pub fn push_round(&mut self, round: Round) {
+        self.rounds.push(round);
+    }
+
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            rounds: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Round {
+    amounts: Amounts,
+}
+
+impl Round {
+    pub fn amounts(&self) -> Amounts {
+        self.amounts
+    }
+
+    pub fn amounts_mut(&mut self) -> &mut Amounts {
+        &mut self.amounts
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Amounts {
+    pub red: u32,
+    pub blue: u32,
+    pub green: u32,
+}
+
+impl Amounts {
+    pub fn set(&mut self, name: &str, amount: u32) -> Result<(), String> {
+        match name {
+            "red" => {
+                self.red = amount;
+            }
+            "blue" => {
+                self.blue = amount;
+            }
+            "green" => {
+                self.green = amount;
+            }
+            _ => {
+                return Err(format!("unknown color `{name}`"));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn max_per_color(self, other: Amounts) -> Amounts {
+        Amounts {
+            red: self.red.max(other.red),
+            blue: self.blue.max(other.blue),
+            green: self.green.max(other.green),
+        }
+    }
+
+    pub fn power(self) -> u64 {
+        (self.red as u64)
+            .checked_mul(self.green as u64)
+            .and_then(|power| power.checked_mul(self.blue as u64))
+            .expect("power overflows u64")
+    }
+
+    pub fn check_validity(self, limit: Amounts) -> bool {
+        self.red <= limit.red && self.green <= limit.green && self.blue <= limit.blue
+    }
+}
+
+fn process_line(line: Line) -> Result<Game, ParseError> {
+    let (game_and_id, rounds_content) = line
+        .text()
+        .split_once(':')
+        .ok_or_else(|| line.error("expected `Game <id>: <rounds>`"))?;
+    let mut second_split = game_and_id.split_whitespace();
+    second_split.next(); // Game
+
+    let id = second_split
+        .next()
+        .ok_or_else(|| line.error_at(game_and_id, "missing game id"))?;
+    let id = line.parse::<u32>(id, "game id")?;
+
+    let mut game = Game::new(id);
+
+    for round_str in rounds_content.split(';') {
+        let mut round = Round::default();
+        for amount in round_str.split(',') {
+            let amount_str = amount.trim();
+            let mut split = amount_str.split_whitespace();
+            let (Some(num), Some(name)) = (split.next(), split.next()) else {
+                return Err(line.error_at(amount, "expected `<amount> <color>`"));
+            };
+            let num = line.parse(num, "amount")?;
+            round
+                .amounts_mut()
+                .set(name, num)
+                .map_err(|e| line.error_at(name, e))?;
+        }
+        game.push_round(round)
+    }
+
+    Ok(game)
+}
+
+fn check_validity(game: &Game, limits: Amounts) -> bool {
+    let valid = game
+        .rounds()
+        .iter()
+        .all(|r| r.amounts().check_validity(limits));
+
+    diag!("game {} is valid: {}", game.id(), valid);
+    valid
+}
+
+const LIMITS: Amounts = Amounts {
+    red: 12,
+    blue: 14,
+    green: 13,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        error::lines(input).map(process_line).collect()
+    }
+
+    fn part1(games: &Self::Input) -> u32 {
+        let mut sum = 0;
+        for game in games {
+            if check_validity(game, LIMITS) {
+                sum = game
+                    .id()
+                    .checked_add(sum)
+                    .expect("sum of game ids overflows u32");
+            }
+        }
+
+        sum
+    }
+
+    fn part2(games: &Self::Input) -> u64 {
+        let mut power_sum = 0;
+        for game in games {
+            let min_amount = game
+                .rounds()
+                .iter()
+                .map(Round::amounts)
+                .reduce(Amounts::max_per_color)
+                .unwrap();
+            power_sum = min_amount
+                .power()
+                .checked_add(power_sum)
+                .expect("sum of powers overflows u64");
+        }
+
+        power_sum
+    }
+}
@@ -0,0 +1,176 @@
+use std::convert::Infallible;
+
+use crate::{
+    error::{self, Line, ParseError},
+    solution::Solution,
+    util::{Grid2d, Index2d},
+};
+
+#[derive(Debug, Copy, Clone)]
+struct NumberRange {
+    pub line: usize,
+    pub start: usize,
+    pub len: usize,
+    pub number: u32,
+}
+
+fn extract_number_range_from_line(line: Line) -> Result<Vec<NumberRange>, ParseError> {
+    let mut ret = vec![];
+
+    let mut next_unseen_index = 0;
+
+    while next_unseen_index < line.text().len() {
+        let remaining_line = &line.text()[next_unseen_index..];
+        let after_non_numeric = remaining_line
+            .char_indices()
+            .skip_while(|(_, c)| !c.is_ascii_digit());
+        let mut num = after_non_numeric.take_while(|(_i, c)| c.is_ascii_digit());
+
+        if let Some((number_start, _)) = num.next() {
+            let number_last = if let Some((last, _)) = num.last() {
+                last
+            } else {
+                number_start
+            };
+
+            let len = number_last - number_start + 1;
+
+            let number = line.parse(&remaining_line[number_start..=number_last], "number")?;
+
+            ret.push(NumberRange {
+                line: line.index(),
+                start: number_start + next_unseen_index,
+                len,
+                number,
+            });
+
+            next_unseen_index += number_last + 1;
+        } else {
+            break;
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Marks every cell that is adjacent to a symbol, including diagonally.
+fn symbol_neighborhood(grid: &Grid2d<char>) -> Grid2d<bool> {
+    let mut ret = Grid2d::new(grid.len_x(), grid.len_y());
+
+    for (index, c) in grid.iter_indexed() {
+        if !c.is_ascii_digit() && *c != '.' {
+            // symbol
+            for neighbor in grid.neighbors8(index) {
+                ret[neighbor] = true;
+            }
+        }
+    }
+
+    ret
+}
+
+fn is_valid(range: NumberRange, neighborhood: &Grid2d<bool>) -> bool {
+    (range.start..range.start + range.len).any(|x| {
+        neighborhood[Index2d {
+            x: x as i32,
+            y: range.line as i32,
+        }]
+    })
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    grid: Grid2d<char>,
+    valid_number_ranges: Vec<NumberRange>,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid2d::parse(input, Ok::<_, Infallible>)?;
+        if let Some(line) = error::lines(input).find(|l| !l.text().is_ascii()) {
+            return Err(line.error("expected only ASCII characters"));
+        }
+
+        let mut number_ranges: Vec<NumberRange> = vec![];
+        for line in error::lines(input) {
+            let ranges = extract_number_range_from_line(line)?;
+            number_ranges.extend(ranges);
+        }
+
+        let neighborhood = symbol_neighborhood(&grid);
+
+        let valid_number_ranges = number_ranges
+            .into_iter()
+            .filter(|r| is_valid(*r, &neighborhood))
+            .collect::<Vec<_>>();
+
+        Ok(Schematic {
+            grid,
+            valid_number_ranges,
+        })
+    }
+
+    fn part1(schematic: &Self::Input) -> u32 {
+        schematic
+            .valid_number_ranges
+            .iter()
+            .try_fold(0u32, |sum, r| sum.checked_add(r.number))
+            .expect("sum of part numbers overflows u32")
+    }
+
+    fn part2(schematic: &Self::Input) -> u64 {
+        let gears = find_gears(&schematic.valid_number_ranges, &schematic.grid);
+        gears
+            .iter()
+            .try_fold(0u64, |sum, g| sum.checked_add(g.ratio))
+            .expect("sum of gear ratios overflows u64")
+    }
+}
+
+#[derive(Debug)]
+struct Gear {
+    pub ratio: u64,
+}
+
+const GEAR_SYMBOL: char = '*';
+
+fn find_gears(valid_number_ranges: &[NumberRange], grid: &Grid2d<char>) -> Vec<Gear> {
+    let mut gears = vec![];
+
+    for (index, c) in grid.iter_indexed() {
+        if *c == GEAR_SYMBOL {
+            // find adjacent number ranges
+            let adjacent_ranges = valid_number_ranges
+                .iter()
+                .filter(|r| is_range_adjacent(**r, index.y as usize, index.x as usize))
+                .collect::<Vec<_>>();
+            if adjacent_ranges.len() == 2 {
+                gears.push(Gear {
+                    // can't overflow: both numbers are u32
+                    ratio: adjacent_ranges[0].number as u64 * adjacent_ranges[1].number as u64,
+                })
+            };
+        }
+    }
+
+    gears
+}
+
+fn is_range_adjacent(range: NumberRange, line_idx: usize, idx_in_line: usize) -> bool {
+    if (range.line as i32 - line_idx as i32).abs() > 1 {
+        return false;
+    }
+
+    for i in range.start..range.start + range.len {
+        if (i as i32 - idx_in_line as i32).abs() < 2 {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use crate::{
+    diag,
+    error::{self, Line, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Card {
+    id: u32,
+    winning_numbers: HashSet<u32>,
+    numbers: Vec<u32>,
+}
+
+impl Card {
+    pub fn correct_numbers(&self) -> Vec<u32> {
+        self.numbers
+            .iter()
+            .filter(|num| self.winning_numbers.contains(num))
+            .cloned()
+            .collect::<Vec<_>>()
+    }
+
+    pub fn from_line(line: Line, id: u32) -> Result<Self, ParseError> {
+        let (_, numbers) = line
+            .text()
+            .split_once(':')
+            .ok_or_else(|| line.error("expected `Card <id>: <numbers>`"))?;
+
+        let (winning_numbers, numbers) = numbers
+            .split_once('|')
+            .ok_or_else(|| line.error_at(numbers, "expected `|` between the number lists"))?;
+
+        let winning_numbers = winning_numbers
+            .split_whitespace()
+            .map(|n| line.parse(n, "number"))
+            .collect::<Result<_, _>>()?;
+        let numbers = numbers
+            .split_whitespace()
+            .map(|n| line.parse(n, "number"))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            id,
+            winning_numbers,
+            numbers,
+        })
+    }
+
+    pub fn score_part_1(&self) -> u32 {
+        let correct_numbers = self.correct_numbers();
+        if correct_numbers.is_empty() {
+            0
+        } else {
+            1u32.checked_shl(correct_numbers.len() as u32 - 1)
+                .expect("score overflows u32")
+        }
+    }
+
+    pub fn update_winning_cards(&self, num_cards: &mut [u32]) {
+        let copies = num_cards[self.id as usize];
+        for i in 0..self.correct_numbers().len() {
+            let won = &mut num_cards[i + 1 + self.id as usize];
+            *won = won
+                .checked_add(copies)
+                .expect("number of copies overflows u32");
+        }
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let cards = error::lines(input)
+            .map(|l| Card::from_line(l, l.index() as _))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // part 2 can't win copies of cards past the end of the table
+        for (line, card) in error::lines(input).zip(&cards) {
+            let won = card.correct_numbers().len();
+            let following = cards.len() - 1 - card.id as usize;
+            if won > following {
+                return Err(line.error(format!(
+                    "card wins copies of the next {won} cards, but there are only {following} after it"
+                )));
+            }
+        }
+
+        Ok(cards)
+    }
+
+    fn part1(cards: &Self::Input) -> u32 {
+        cards
+            .iter()
+            .map(Card::score_part_1)
+            .try_fold(0u32, u32::checked_add)
+            .expect("total score overflows u32")
+    }
+
+    fn part2(cards: &Self::Input) -> u32 {
+        let mut copies_per_card = vec![1; cards.len()];
+        for card in cards {
+            card.update_winning_cards(&mut copies_per_card);
+        }
+        diag!("{copies_per_card:?}");
+        copies_per_card
+            .iter()
+            .try_fold(0u32, |total, copies| total.checked_add(*copies))
+            .expect("total number of cards overflows u32")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reject_copies_past_the_end() {
+        let error = Day04::parse("Card 1: 1 2 3 | 1 2 3\nCard 2: 5 | 6").unwrap_err();
+        assert_eq!(error.line(), 0);
+        assert!(Day04::parse("Card 1: 1 2 | 1 3\nCard 2: 5 | 6").is_ok());
+    }
+}
@@ -0,0 +1,151 @@
+use std::ops::Range;
+
+use crate::{
+    diag,
+    error::{self, ParseError},
+    interval::RangeMap,
+    solution::Solution,
+};
+
+const NUM_MAPS: usize = 7;
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    /// The seeds read as pairs of start and length for part 2.
+    seed_ranges: Vec<Range<u64>>,
+    /// All maps composed into one, mapping seeds directly to locations.
+    seed_to_location: RangeMap,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = error::lines(input);
+        let seeds_line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(0, None, "empty input"))?;
+        let seeds = seeds_line
+            .text()
+            .strip_prefix("seeds:")
+            .ok_or_else(|| seeds_line.error("expected `seeds: <numbers>`"))?;
+
+        let seed_parts = seeds.split_whitespace().collect::<Vec<_>>();
+        let seeds: Vec<u64> = seed_parts
+            .iter()
+            .map(|s| seeds_line.parse(s, "seed"))
+            .collect::<Result<_, _>>()?;
+        if seeds.is_empty() {
+            return Err(seeds_line.error("expected at least one seed"));
+        }
+        if !seeds.len().is_multiple_of(2) {
+            return Err(seeds_line.error(format!(
+                "expected pairs of seed range start and length, found {} numbers",
+                seeds.len()
+            )));
+        }
+
+        let mut seed_ranges = vec![];
+        for (pair, parts) in seeds.chunks_exact(2).zip(seed_parts.chunks_exact(2)) {
+            let (start, len) = (pair[0], pair[1]);
+            if len == 0 {
+                return Err(seeds_line.error_at(parts[1], "empty seed range"));
+            }
+            let end = start
+                .checked_add(len)
+                .ok_or_else(|| seeds_line.error_at(parts[1], "seed range end overflows u64"))?;
+            seed_ranges.push(start..end);
+        }
+
+        let mut map_vec: Vec<RangeMap> = vec![];
+
+        for line in lines {
+            let text = line.text();
+            if text.trim().is_empty() {
+                continue;
+            }
+            if text.ends_with("map:") {
+                map_vec.push(RangeMap::default());
+                continue;
+            }
+
+            let range_map = map_vec
+                .last_mut()
+                .ok_or_else(|| line.error("expected a map declaration"))?;
+            let nums = text
+                .split_whitespace()
+                .map(|s| line.parse::<u64>(s, "number"))
+                .collect::<Result<Vec<_>, _>>()?;
+            let [dest, src, len] = nums[..] else {
+                return Err(line.error("expected `<destination> <source> <length>`"));
+            };
+            range_map
+                .insert(dest, src, len)
+                .map_err(|e| line.error(e))?;
+        }
+
+        if map_vec.len() != NUM_MAPS {
+            return Err(ParseError::new(
+                input.lines().count(),
+                None,
+                format!("expected {NUM_MAPS} maps, found {}", map_vec.len()),
+            ));
+        }
+
+        let seed_to_location = map_vec
+            .iter()
+            .fold(RangeMap::default(), |acc, map| acc.compose(map));
+
+        Ok(Almanac {
+            seeds,
+            seed_ranges,
+            seed_to_location,
+        })
+    }
+
+    fn part1(almanac: &Self::Input) -> u64 {
+        almanac
+            .seeds
+            .iter()
+            .map(|seed| almanac.seed_to_location.get(*seed))
+            .min()
+            .expect("there is at least one seed")
+    }
+
+    fn part2(almanac: &Self::Input) -> u64 {
+        diag!("{:?}", almanac.seed_ranges);
+
+        almanac
+            .seed_to_location
+            .map_ranges(almanac.seed_ranges.iter().cloned())
+            .iter()
+            .map(|r| r.start)
+            .min()
+            .expect("there is at least one non-empty seed range")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reject_invalid_seeds() {
+        let maps = "\n\na map:\n\nb map:\n\nc map:\n\nd map:\n\ne map:\n\nf map:\n\ng map:\n";
+        let error = |seeds: &str| Day05::parse(&format!("{seeds}{maps}")).unwrap_err();
+
+        assert_eq!(error("seeds:").message(), "expected at least one seed");
+        assert_eq!(
+            error("seeds: 1 2 3").message(),
+            "expected pairs of seed range start and length, found 3 numbers"
+        );
+        assert_eq!(error("seeds: 1 2 3 0").column(), Some(13));
+        let almanac = Day05::parse(&format!("seeds: 7 2{maps}")).unwrap();
+        assert_eq!((Day05::part1(&almanac), Day05::part2(&almanac)), (2, 7));
+    }
+}
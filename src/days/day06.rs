@@ -0,0 +1,170 @@
+use crate::{
+    diag,
+    error::{self, Line, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug)]
+pub struct Races {
+    // time and record distance per race
+    races: Vec<(u64, u64)>,
+    // for part 2, the spaces between the numbers are to be ignored, so there's only one race
+    single_race: (u64, u64),
+}
+
+/// Parses a line like `Time: 7 15 30` into its numbers and the number formed by concatenating them.
+fn parse_number_line(line: Option<Line>, name: &str) -> Result<(Vec<u64>, u64), ParseError> {
+    let line = line.ok_or_else(|| ParseError::new(0, None, format!("missing `{name}` line")))?;
+    let numbers = line
+        .text()
+        .strip_prefix(name)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .ok_or_else(|| line.error(format!("expected `{name}: <numbers>`")))?;
+
+    let list = numbers
+        .split_whitespace()
+        .map(|s| line.parse(s, "number"))
+        .collect::<Result<_, _>>()?;
+
+    let concatenated = numbers.split_whitespace().collect::<String>();
+    let concatenated = concatenated
+        .parse()
+        .map_err(|_| line.error_at(numbers, format!("`{concatenated}` is not a valid number")))?;
+
+    Ok((list, concatenated))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Races;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = error::lines(input);
+        let (times, single_time) = parse_number_line(lines.next(), "Time")?;
+        let (distances, single_distance) = parse_number_line(lines.next(), "Distance")?;
+
+        if times.len() != distances.len() {
+            return Err(ParseError::new(
+                1,
+                None,
+                format!(
+                    "found {} times, but {} distances",
+                    times.len(),
+                    distances.len()
+                ),
+            ));
+        }
+
+        let race_data = times.into_iter().zip(distances).collect::<Vec<_>>();
+
+        diag!("race data: {race_data:?}");
+
+        Ok(Races {
+            races: race_data,
+            single_race: (single_time, single_distance),
+        })
+    }
+
+    fn part1(races: &Self::Input) -> u64 {
+        find_ways_to_win_product(&races.races)
+    }
+
+    fn part2(races: &Self::Input) -> u64 {
+        diag!("single race data (pt 2): {:?}", races.single_race);
+
+        find_ways_to_win_product(&[races.single_race])
+    }
+}
+
+fn find_ways_to_win_product(race_data: &[(u64, u64)]) -> u64 {
+    let mut product: u64 = 1;
+    for (time, target) in race_data.iter().cloned() {
+        product = ways_to_win(time, target)
+            .checked_mul(product)
+            .expect("product of ways to win overflows u64");
+    }
+
+    product
+}
+
+/// The number of hold times that beat `target` in a race lasting `time`, 0 if the race can't be won.
+fn ways_to_win(time: u64, target: u64) -> u64 {
+    match first_winning_hold(time, target) {
+        Some(first_greater) => {
+            let last_greater = time - first_greater;
+            last_greater - first_greater + 1
+        }
+        None => 0,
+    }
+}
+
+/// The shortest hold time that beats `target` in a race lasting `time`, if any.
+///
+/// Holding for `i` wins iff `i * (time - i) > target`, i.e. iff `i` lies strictly between the roots
+/// `(time ± sqrt(time² - 4 * target)) / 2`. The integer square root puts us within a step of the
+/// lower root, and the exact check settles the rest, so ties never count as wins.
+fn first_winning_hold(time: u64, target: u64) -> Option<u64> {
+    let (time, target) = (u128::from(time), u128::from(target));
+    let wins = |i: u128| i * (time - i) > target;
+
+    let discriminant = (time * time).checked_sub(4 * target)?;
+    let mut first = (time - discriminant.isqrt()) / 2;
+    while first > 0 && wins(first - 1) {
+        first -= 1;
+    }
+    while first <= time / 2 && !wins(first) {
+        first += 1;
+    }
+
+    // the distance is largest at half the race time, so if that doesn't win, nothing does
+    (first <= time / 2).then_some(first as u64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn first_winning_hold_linear(time: u64, target: u64) -> Option<u64> {
+        (0..time).find(|i| i * (time - i) > target)
+    }
+
+    #[test]
+    fn test_first_winning_hold_matches_linear_scan() {
+        for time in 0..60 {
+            for target in 0..1000 {
+                assert_eq!(
+                    first_winning_hold(time, target),
+                    first_winning_hold_linear(time, target),
+                    "time {time}, target {target}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_first_winning_hold_large() {
+        // 30 * 20 = 600 only ties the record
+        assert_eq!(first_winning_hold(50, 600), Some(21));
+        assert_eq!(first_winning_hold(50, 625), None);
+        assert_eq!(first_winning_hold(u64::MAX, u64::MAX), Some(2));
+        let time = 71_530_000;
+        let target = 940_200_000_000;
+        assert_eq!(
+            first_winning_hold(time, target),
+            first_winning_hold_linear(time, target)
+        );
+    }
+
+    #[test]
+    fn test_unwinnable_race() {
+        assert_eq!(ways_to_win(7, 9), 4);
+        assert_eq!(ways_to_win(5, 100), 0);
+
+        let races = Day06::parse("Time: 5 7\nDistance: 100 9").unwrap();
+        assert_eq!(Day06::part1(&races), 0);
+        assert_eq!(Day06::part2(&races), 0);
+    }
+}
@@ -0,0 +1,248 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{
+    diag,
+    error::{self, ParseError},
+    solution::Solution,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    // cards and bid per hand
+    type Input = Vec<(String, u32)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // both parts use the same cards and hand size, so either rules can check the hands
+        let rules = Rules::part1();
+        error::lines(input)
+            .map(|line| {
+                let mut parts = line.text().split_whitespace();
+                let (Some(cards), Some(bid)) = (parts.next(), parts.next()) else {
+                    return Err(line.error("expected `<cards> <bid>`"));
+                };
+
+                match rules.evaluate(cards) {
+                    Ok(_) => {}
+                    Err(e @ HandError::UnknownCard { position, .. }) => {
+                        return Err(line.error_at(&cards[position..], e.to_string()))
+                    }
+                    Err(e) => return Err(line.error_at(cards, e.to_string())),
+                }
+
+                let bid = line.parse(bid, "bid")?;
+                Ok((cards.to_owned(), bid))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        total_winnings(input, &Rules::part1())
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        total_winnings(input, &Rules::part2())
+    }
+}
+
+fn total_winnings(input: &[(String, u32)], rules: &Rules) -> u32 {
+    let mut hands = input
+        .iter()
+        .map(|(cards, bid)| {
+            let hand = rules
+                .evaluate(cards)
+                .expect("hands are checked while parsing");
+            (hand, *bid)
+        })
+        .collect::<Vec<_>>();
+
+    hands.sort_by(|(a, _), (b, _)| a.cmp(b));
+    for (hand, _) in &hands {
+        diag!("hand type {}, card ranks: {:?}", hand.signature, hand.ranks)
+    }
+
+    hands
+        .iter()
+        .enumerate()
+        .try_fold(0u32, |total, (i, (_, bid))| {
+            u32::try_from(i + 1)
+                .ok()
+                .and_then(|rank| rank.checked_mul(*bid))
+                .and_then(|winnings| total.checked_add(winnings))
+        })
+        .expect("total winnings overflow u32")
+}
+
+/// Configuration of a Camel Cards variant.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    /// Rank of every card; higher is stronger.
+    ranks: HashMap<char, usize>,
+    /// Cards that act like whatever card makes the hand strongest.
+    wildcards: Vec<char>,
+    hand_size: usize,
+}
+
+impl Rules {
+    /// `card_order` lists all cards from strongest to weakest. Wildcards have to be part of it.
+    pub fn new(card_order: &str, wildcards: &str, hand_size: usize) -> Result<Self, String> {
+        let mut ranks = HashMap::new();
+        for (rank, card) in card_order.chars().rev().enumerate() {
+            if ranks.insert(card, rank).is_some() {
+                return Err(format!("card `{card}` is listed twice"));
+            }
+        }
+        if let Some(card) = wildcards.chars().find(|c| !ranks.contains_key(c)) {
+            return Err(format!("wildcard `{card}` is not one of the cards"));
+        }
+
+        Ok(Self {
+            ranks,
+            wildcards: wildcards.chars().collect(),
+            hand_size,
+        })
+    }
+
+    /// Five cards without wildcards.
+    pub fn part1() -> Self {
+        Self::new("AKQJT98765432", "", 5).expect("valid rules")
+    }
+
+    /// Jacks become jokers: wildcards, but the weakest card when comparing card by card.
+    pub fn part2() -> Self {
+        Self::new("AKQT98765432J", "J", 5).expect("valid rules")
+    }
+
+    pub fn evaluate(&self, cards: &str) -> Result<Hand, HandError> {
+        let ranks = cards
+            .char_indices()
+            .map(|(position, card)| {
+                self.ranks
+                    .get(&card)
+                    .copied()
+                    .ok_or(HandError::UnknownCard { position, card })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if ranks.len() != self.hand_size {
+            return Err(HandError::WrongSize {
+                expected: self.hand_size,
+                found: ranks.len(),
+            });
+        }
+
+        let mut frequencies = HashMap::<char, usize>::new();
+        let mut num_wildcards = 0;
+        for card in cards.chars() {
+            if self.wildcards.contains(&card) {
+                num_wildcards += 1;
+            } else {
+                *frequencies.entry(card).or_default() += 1;
+            }
+        }
+
+        let mut counts = frequencies.into_values().collect::<Vec<_>>();
+        counts.sort_by(|a, b| b.cmp(a));
+        // wildcards are always best used to extend the largest group
+        match counts.first_mut() {
+            Some(largest) => *largest += num_wildcards,
+            None if num_wildcards > 0 => counts.push(num_wildcards),
+            None => {}
+        }
+
+        Ok(Hand {
+            signature: Signature(counts),
+            ranks,
+        })
+    }
+}
+
+/// Why cards don't form a valid hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandError {
+    UnknownCard { position: usize, card: char },
+    WrongSize { expected: usize, found: usize },
+}
+
+impl Display for HandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HandError::UnknownCard { card, .. } => write!(f, "unknown card `{card}`"),
+            HandError::WrongSize { expected, found } => {
+                write!(f, "expected {expected} cards, found {found}")
+            }
+        }
+    }
+}
+
+/// The type of a hand: how many cards of each kind there are, largest group first.
+/// Comparing these lexicographically gives the usual order, e.g. a full house `[3, 2]` beats
+/// three of a kind `[3, 1, 1]`, for any hand size.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Signature(pub Vec<usize>);
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let counts = self.0.iter().map(usize::to_string).collect::<Vec<_>>();
+        write!(f, "{}", counts.join("+"))
+    }
+}
+
+/// An evaluated hand. Hands are ordered by type first, then card by card.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Hand {
+    pub signature: Signature,
+    pub ranks: Vec<usize>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn signature(rules: &Rules, cards: &str) -> Vec<usize> {
+        rules.evaluate(cards).unwrap().signature.0
+    }
+
+    #[test]
+    fn test_signatures() {
+        let rules = Rules::part2();
+        assert_eq!(signature(&rules, "32T3K"), [2, 1, 1, 1]);
+        assert_eq!(signature(&rules, "KTJJT"), [4, 1]);
+        assert_eq!(signature(&rules, "JJJJJ"), [5]);
+        assert_eq!(signature(&Rules::part1(), "KTJJT"), [2, 2, 1]);
+    }
+
+    #[test]
+    fn test_order() {
+        let rules = Rules::part2();
+        let hand = |cards| rules.evaluate(cards).unwrap();
+        // full house beats three of a kind, and jokers are weakest when comparing card by card
+        assert!(hand("22333") > hand("AAA23"));
+        assert!(hand("JKKK2") < hand("QQQQ2"));
+    }
+
+    #[test]
+    fn test_variant_rules() {
+        // three card hands where both 1 and 2 are wild
+        let rules = Rules::new("ABC12", "12", 3).unwrap();
+        assert_eq!(signature(&rules, "A1C"), [2, 1]);
+        assert_eq!(signature(&rules, "12B"), [3]);
+        assert_eq!(
+            rules.evaluate("ABCA"),
+            Err(HandError::WrongSize {
+                expected: 3,
+                found: 4
+            })
+        );
+        assert_eq!(
+            rules.evaluate("AXB"),
+            Err(HandError::UnknownCard {
+                position: 1,
+                card: 'X'
+            })
+        );
+        assert!(Rules::new("AB", "C", 2).is_err());
+        assert!(Rules::new("ABA", "", 2).is_err());
+    }
+}
@@ -0,0 +1,525 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Write},
+};
+
+use crate::{
+    bigint::BigInt,
+    crt::Congruence,
+    cycle::{self, Cycle},
+    diag,
+    error::{self, ParseError},
+    solution::{Part, Solution, Style},
+};
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    left: usize,
+    right: usize,
+    name: String,
+}
+
+#[derive(Debug, Default)]
+struct Network {
+    nodes: Vec<Node>,
+    name_to_id: HashMap<String, usize>,
+}
+
+impl Network {
+    fn get_or_insert(&mut self, name: &str) -> usize {
+        let id = *self
+            .name_to_id
+            .entry(name.to_owned())
+            .or_insert(self.nodes.len());
+
+        if id >= self.nodes.len() {
+            self.nodes.push(Node {
+                left: 0,
+                right: 0,
+                name: name.to_owned(),
+            })
+        }
+
+        id
+    }
+
+    fn insert_edges(&mut self, name: &str, left: &str, right: &str) {
+        let start = self.get_or_insert(name);
+        let left = self.get_or_insert(left);
+        let right = self.get_or_insert(right);
+
+        self.nodes[start].left = left;
+        self.nodes[start].right = right;
+    }
+
+    fn node_neighbor(&self, node: usize, direction: Direction) -> usize {
+        let node = &self.nodes[node];
+        match direction {
+            Direction::Left => node.left,
+            Direction::Right => node.right,
+        }
+    }
+
+    fn node_name(&self, node: usize) -> &str {
+        &self.nodes[node].name
+    }
+}
+
+/// A node together with the position in the directions we're at.
+/// Once one of these repeats, the path loops.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+struct IterationNode {
+    node: usize,
+    relative_iteration: usize,
+}
+
+/// Answer to part 1: the start or target node might not exist, or the target might never be reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Steps {
+    Steps(usize),
+    MissingNode(&'static str),
+    Unreachable,
+}
+
+impl Display for Steps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Steps::Steps(steps) => write!(f, "{steps}"),
+            Steps::MissingNode(name) => write!(f, "no `{name}` node"),
+            Steps::Unreachable => write!(f, "target is never reached"),
+        }
+    }
+}
+
+/// Follows the directions from `start` until `target` is reached.
+fn count_steps(map: &Map, start: &'static str, target: &'static str) -> Steps {
+    let node = |name| map.network.name_to_id.get(name).copied();
+    let Some(start) = node(start) else {
+        return Steps::MissingNode(start);
+    };
+    let Some(target) = node(target) else {
+        return Steps::MissingNode(target);
+    };
+
+    // once the walk runs in a loop without having reached the target, it never will
+    ghost_schedule(map, start, |node| node == target)
+        .hits
+        .first()
+        .map_or(Steps::Unreachable, |steps| Steps::Steps(*steps))
+}
+
+#[derive(Debug)]
+pub struct Map {
+    directions: Vec<Direction>,
+    network: Network,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Map;
+    type Answer1 = Steps;
+    type Answer2 = GhostSteps;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = error::lines(input);
+        let directions_line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(0, None, "empty input"))?;
+        let directions: Vec<Direction> = directions_line
+            .text()
+            .char_indices()
+            .map(|(i, c)| {
+                Direction::try_from(c).map_err(|_| {
+                    directions_line.error_at_column(i, format!("unknown direction `{c}`"))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        if directions.is_empty() {
+            return Err(directions_line.error("expected at least one direction"));
+        }
+
+        let mut network = Network::default();
+        let mut defined_nodes = HashSet::new();
+        let mut referenced_nodes = vec![];
+        for line in lines.filter(|l| !l.text().trim().is_empty()) {
+            let parsed = line.text().split_once('=').and_then(|(node, rest)| {
+                let (left, right) = rest
+                    .trim()
+                    .strip_prefix('(')?
+                    .strip_suffix(')')?
+                    .split_once(',')?;
+                Some((node.trim(), left.trim(), right.trim()))
+            });
+            let Some((node, left, right)) = parsed else {
+                return Err(line.error("expected `<node> = (<left>, <right>)`"));
+            };
+
+            if !defined_nodes.insert(node) {
+                return Err(line.error_at(node, format!("node `{node}` is defined twice")));
+            }
+            referenced_nodes.push((line, left));
+            referenced_nodes.push((line, right));
+
+            network.insert_edges(node, left, right);
+        }
+
+        if let Some((line, node)) = referenced_nodes
+            .into_iter()
+            .find(|(_, node)| !defined_nodes.contains(node))
+        {
+            return Err(line.error_at(node, format!("node `{node}` is never defined")));
+        }
+
+        Ok(Map {
+            directions,
+            network,
+        })
+    }
+
+    fn part1(map: &Self::Input) -> Steps {
+        count_steps(map, "AAA", "ZZZ")
+    }
+
+    fn part2(map: &Self::Input) -> GhostSteps {
+        count_ghost_steps(map)
+    }
+
+    /// The network as a Graphviz DOT graph, preceded by the cycle report as comments.
+    fn render(map: &Self::Input, part: Part, _style: Style) -> Option<String> {
+        let (starts, targets) = start_and_target_nodes(&map.network, part);
+        if starts.is_empty() {
+            return None;
+        }
+
+        let schedules = starts
+            .iter()
+            .map(|start| ghost_schedule(map, *start, |node| targets.contains(&node)))
+            .collect::<Vec<_>>();
+
+        let mut rendered = String::new();
+        for line in cycle_report(&map.network, &starts, &schedules) {
+            writeln!(rendered, "// {line}").unwrap();
+        }
+        rendered.push_str(&to_dot(&map.network, &starts, &targets, &schedules));
+        Some(rendered)
+    }
+}
+
+/// Nodes the walk starts from, sorted by name, and the nodes it tries to reach:
+/// `AAA` and `ZZZ` in part 1, nodes ending in `A` and in `Z` for the ghosts of part 2.
+fn start_and_target_nodes(network: &Network, part: Part) -> (Vec<usize>, HashSet<usize>) {
+    let is_start = |name: &str| match part {
+        Part::One => name == "AAA",
+        Part::Two => name.ends_with('A'),
+    };
+    let is_target = |name: &str| match part {
+        Part::One => name == "ZZZ",
+        Part::Two => name.ends_with('Z'),
+    };
+
+    let mut starts = (0..network.nodes.len())
+        .filter(|node| is_start(network.node_name(*node)))
+        .collect::<Vec<_>>();
+    starts.sort_by_key(|node| network.node_name(*node));
+    let targets = (0..network.nodes.len())
+        .filter(|node| is_target(network.node_name(*node)))
+        .collect();
+
+    (starts, targets)
+}
+
+/// One line per start node: how long it takes to enter the loop, how long the loop is
+/// and at which steps the walk is on a target.
+fn cycle_report(network: &Network, starts: &[usize], schedules: &[GhostSchedule]) -> Vec<String> {
+    starts
+        .iter()
+        .zip(schedules)
+        .map(|(start, schedule)| {
+            let (tail_hits, loop_hits): (Vec<usize>, Vec<usize>) = schedule
+                .hits
+                .iter()
+                .partition(|hit| **hit < schedule.cycle.start);
+            format!(
+                "{}: tail length {}, loop length {}, on a target at steps {:?} in the tail and {:?} in the loop",
+                network.node_name(*start),
+                schedule.cycle.start,
+                schedule.cycle.length,
+                tail_hits,
+                loop_hits
+            )
+        })
+        .collect()
+}
+
+/// Colors of the loops in [`to_dot`], reused if there are more start nodes.
+const LOOP_COLORS: [&str; 6] = ["red", "blue", "darkgreen", "orange", "purple", "brown"];
+
+/// Exports the network as a Graphviz DOT graph. Start nodes are filled green, targets red,
+/// and the edges of the loop each start node ends up in are drawn in that start's color.
+fn to_dot(
+    network: &Network,
+    starts: &[usize],
+    targets: &HashSet<usize>,
+    schedules: &[GhostSchedule],
+) -> String {
+    let mut edge_colors: HashMap<(usize, Direction), Vec<&str>> = HashMap::new();
+    for (schedule, color) in schedules.iter().zip(LOOP_COLORS.iter().cycle()) {
+        for edge in schedule.loop_edges.iter().collect::<HashSet<_>>() {
+            edge_colors.entry(*edge).or_default().push(color);
+        }
+    }
+
+    let quote = |node| format!("\"{}\"", network.node_name(node).escape_default());
+
+    let mut dot = String::from("digraph network {\n");
+    for node in 0..network.nodes.len() {
+        let style = if starts.contains(&node) {
+            " [style=filled, fillcolor=palegreen]"
+        } else if targets.contains(&node) {
+            " [style=filled, fillcolor=lightcoral]"
+        } else {
+            ""
+        };
+        writeln!(dot, "    {}{style};", quote(node)).unwrap();
+    }
+    for node in 0..network.nodes.len() {
+        for (direction, label) in [(Direction::Left, "L"), (Direction::Right, "R")] {
+            let neighbor = network.node_neighbor(node, direction);
+            let color = match edge_colors.get(&(node, direction)) {
+                Some(colors) => format!(", color=\"{}\", penwidth=2", colors.join(":")),
+                None => String::new(),
+            };
+            writeln!(
+                dot,
+                "    {} -> {} [label=\"{label}\"{color}];",
+                quote(node),
+                quote(neighbor)
+            )
+            .unwrap();
+        }
+    }
+    dot.push('}');
+    dot
+}
+
+/// Answer to part 2: the ghosts might never all be on target nodes at the same time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GhostSteps {
+    Steps(BigInt),
+    NeverSimultaneous,
+}
+
+impl Display for GhostSteps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GhostSteps::Steps(steps) => write!(f, "{steps}"),
+            GhostSteps::NeverSimultaneous => write!(f, "no simultaneous arrival"),
+        }
+    }
+}
+
+/// The steps at which a single ghost is on a target node.
+#[derive(Debug)]
+struct GhostSchedule {
+    cycle: Cycle,
+    /// Steps before the ghost has moved around its loop once at which it is on a target.
+    /// Hits at or after the loop start repeat every loop length.
+    hits: Vec<usize>,
+    /// The edges followed once around the loop.
+    loop_edges: Vec<(usize, Direction)>,
+}
+
+impl GhostSchedule {
+    fn hits_at(&self, step: usize) -> bool {
+        self.hits.binary_search(&self.cycle.reduce(step)).is_ok()
+    }
+
+    /// Hits that happen only once, before the ghost enters its loop.
+    fn early_hits(&self) -> impl Iterator<Item = usize> + '_ {
+        self.hits
+            .iter()
+            .copied()
+            .filter(|hit| *hit < self.cycle.start)
+    }
+
+    /// Once the ghost is on its loop, it's on a target exactly at the steps satisfying one of these.
+    fn loop_hits(&self) -> Vec<Congruence> {
+        self.hits
+            .iter()
+            .filter(|hit| **hit >= self.cycle.start)
+            .map(|hit| Congruence::new(BigInt::from(*hit), BigInt::from(self.cycle.length)))
+            .collect()
+    }
+}
+
+/// Follows the directions from `start` until the ghost runs in a loop, remembering when it's on a target.
+fn ghost_schedule(map: &Map, start: usize, is_target: impl Fn(usize) -> bool) -> GhostSchedule {
+    let network = &map.network;
+    let directions = &map.directions;
+
+    let history = cycle::detect(
+        IterationNode {
+            node: start,
+            relative_iteration: 0,
+        },
+        |n| IterationNode {
+            node: network.node_neighbor(n.node, directions[n.relative_iteration]),
+            relative_iteration: (n.relative_iteration + 1) % directions.len(),
+        },
+    );
+
+    let hits = history
+        .states()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, n)| is_target(n.node).then_some(i))
+        .collect();
+
+    let cycle = history.cycle();
+    let loop_edges = history.states()[cycle.start..]
+        .iter()
+        .map(|n| (n.node, directions[n.relative_iteration]))
+        .collect();
+
+    GhostSchedule {
+        cycle,
+        hits,
+        loop_edges,
+    }
+}
+
+/// Counts the steps until all nodes ending in `A` simultaneously reach nodes ending in `Z`.
+fn count_ghost_steps(map: &Map) -> GhostSteps {
+    let network = &map.network;
+
+    let (start_nodes, targets) = start_and_target_nodes(network, Part::Two);
+
+    let schedules = start_nodes
+        .iter()
+        .map(|start| ghost_schedule(map, *start, |node| targets.contains(&node)))
+        .collect::<Vec<_>>();
+
+    for line in cycle_report(network, &start_nodes, &schedules) {
+        diag!("ghost starting at {line}");
+    }
+
+    let Some(last_to_loop) = schedules.iter().max_by_key(|s| s.cycle.start) else {
+        // no ghosts, so they're all there right away
+        return GhostSteps::Steps(BigInt::zero());
+    };
+
+    // before all ghosts are on their loops, the ghost that enters its loop last
+    // can only be on a target at one of its early hits.
+    if let Some(step) = last_to_loop
+        .early_hits()
+        .find(|step| schedules.iter().all(|s| s.hits_at(*step)))
+    {
+        return GhostSteps::Steps(BigInt::from(step));
+    }
+
+    // afterwards, every ghost has to be at one of its loop hits.
+    // combine the options for all ghosts, dropping combinations that can't happen at the same time.
+    let mut combined = HashSet::from([Congruence::new(BigInt::zero(), BigInt::one())]);
+    for schedule in &schedules {
+        let loop_hits = schedule.loop_hits();
+        combined = combined
+            .iter()
+            .flat_map(|c| loop_hits.iter().filter_map(|hit| c.combine(hit)))
+            .collect();
+    }
+
+    let earliest = BigInt::from(last_to_loop.cycle.start);
+    combined
+        .iter()
+        .map(|c| c.smallest_at_least(&earliest))
+        .min()
+        .map_or(GhostSteps::NeverSimultaneous, GhostSteps::Steps)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gcd::Gcd;
+
+    #[test]
+    fn test_gdc() {
+        assert_eq!(2u32.gcd(4), 2);
+        assert_eq!(2u32.gcd(5), 1);
+        assert_eq!(13u32.gcd(5), 1);
+        assert_eq!(32u32.gcd(12), 4);
+    }
+
+    #[test]
+    fn test_steps_without_path() {
+        let map = Day08::parse("L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)").unwrap();
+        assert_eq!(Day08::part1(&map), Steps::MissingNode("AAA"));
+
+        let map =
+            Day08::parse("L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(Day08::part1(&map), Steps::Unreachable);
+    }
+
+    #[test]
+    fn test_ghost_steps_with_offsets() {
+        // 11A reaches its target after 2 steps and then every 3 steps,
+        // 22A reaches its target after 1 step and then every 2 steps: 5 is the first common step.
+        let map = Day08::parse(
+            "L\n\n11A = (11B, XXX)\n11B = (11Z, XXX)\n11Z = (11C, XXX)\n11C = (11B, XXX)\n\
+             22A = (22Z, XXX)\n22Z = (22B, XXX)\n22B = (22Z, XXX)\nXXX = (XXX, XXX)",
+        )
+        .unwrap();
+        assert_eq!(Day08::part2(&map), GhostSteps::Steps(BigInt::from(5)));
+    }
+
+    #[test]
+    fn test_ghost_steps_never_simultaneous() {
+        // 11A is on its target at even steps, 22A at odd steps
+        let map = Day08::parse(
+            "L\n\n11A = (11Z, XXX)\n11Z = (11A, XXX)\n22A = (22B, XXX)\n22B = (22Z, XXX)\n\
+             22Z = (22B, XXX)\nXXX = (XXX, XXX)",
+        )
+        .unwrap();
+        assert_eq!(Day08::part2(&map), GhostSteps::NeverSimultaneous);
+    }
+
+    #[test]
+    fn test_render_dot() {
+        let map =
+            Day08::parse("L\n\nAAA = (BBB, BBB)\nBBB = (ZZZ, ZZZ)\nZZZ = (BBB, ZZZ)").unwrap();
+        let rendered = Day08::render(&map, Part::One, Style::Plain).unwrap();
+        assert_eq!(
+            rendered,
+            "// AAA: tail length 1, loop length 2, on a target at steps [] in the tail and [2] in the loop\n\
+             digraph network {\n    \
+             \"AAA\" [style=filled, fillcolor=palegreen];\n    \
+             \"BBB\";\n    \
+             \"ZZZ\" [style=filled, fillcolor=lightcoral];\n    \
+             \"AAA\" -> \"BBB\" [label=\"L\"];\n    \
+             \"AAA\" -> \"BBB\" [label=\"R\"];\n    \
+             \"BBB\" -> \"ZZZ\" [label=\"L\", color=\"red\", penwidth=2];\n    \
+             \"BBB\" -> \"ZZZ\" [label=\"R\"];\n    \
+             \"ZZZ\" -> \"BBB\" [label=\"L\", color=\"red\", penwidth=2];\n    \
+             \"ZZZ\" -> \"ZZZ\" [label=\"R\"];\n\
+             }"
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt::Display;
+
+use crate::{
+    bigint::BigInt,
+    diag,
+    error::{self, ParseError},
+    solution::Solution,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Sequence>;
+    type Answer1 = BigInt;
+    type Answer2 = BigInt;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        error::lines(input)
+            .map(|line| {
+                let sequence = line
+                    .text()
+                    .split_whitespace()
+                    .map(|s| line.parse::<BigInt>(s, "number"))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if sequence.is_empty() {
+                    return Err(line.error("empty sequence"));
+                }
+                let polynomial =
+                    Polynomial::fit(&sequence).map_err(|e| line.error(e.to_string()))?;
+                match polynomial.degree() {
+                    Some(degree) => {
+                        diag!("sequence of {} values has degree {degree}", sequence.len())
+                    }
+                    None => diag!("sequence of {} values is all zeros", sequence.len()),
+                }
+                Ok(Sequence {
+                    len: sequence.len(),
+                    polynomial,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(sequences: &Self::Input) -> BigInt {
+        sum_extrapolated(sequences, |sequence| sequence.len as i64)
+    }
+
+    fn part2(sequences: &Self::Input) -> BigInt {
+        sum_extrapolated(sequences, |_| -1)
+    }
+}
+
+/// A sequence from the input, as the polynomial through its `len` values.
+#[derive(Debug)]
+pub struct Sequence {
+    len: usize,
+    polynomial: Polynomial,
+}
+
+/// Sums the values of the sequences at the position `position` returns for each.
+fn sum_extrapolated(sequences: &[Sequence], position: impl Fn(&Sequence) -> i64) -> BigInt {
+    let mut sum = BigInt::zero();
+    for sequence in sequences {
+        sum += &sequence.polynomial.at(position(sequence));
+    }
+    sum
+}
+
+/// Why no polynomial could be fit to a sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FitError {
+    /// The differences never become all zeros, so the values don't determine the polynomial.
+    NoZeroLayer { len: usize },
+}
+
+impl Display for FitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FitError::NoZeroLayer { len } => write!(
+                f,
+                "the differences of these {len} values never become all zeros, \
+                 so they don't determine a polynomial"
+            ),
+        }
+    }
+}
+
+/// The polynomial of minimal degree through the values of a sequence at `0, 1, 2, ...`,
+/// in Newton form: `p(x) = Σ Δᵏp(0) * binomial(x, k)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    /// The forward differences `Δᵏp(0)`, for `k` up to the degree.
+    differences: Vec<BigInt>,
+}
+
+impl Polynomial {
+    /// Takes differences until they are all zeros. Fails if that never happens,
+    /// i.e. the last difference left is still not zero.
+    pub fn fit(values: &[BigInt]) -> Result<Self, FitError> {
+        let mut differences = vec![];
+        let mut layer = values.to_vec();
+        while layer.iter().any(|x| !x.is_zero()) {
+            if layer.len() < 2 {
+                return Err(FitError::NoZeroLayer { len: values.len() });
+            }
+            differences.push(layer[0].clone());
+            layer = layer.windows(2).map(|w| &w[1] - &w[0]).collect();
+        }
+
+        Ok(Self { differences })
+    }
+
+    /// `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.differences.len().checked_sub(1)
+    }
+
+    /// The value at `x`, which can be before the first value (negative) or after the last one.
+    pub fn at(&self, x: i64) -> BigInt {
+        let x = BigInt::from(x);
+        let mut value = BigInt::zero();
+        // binomial(x, k), updated as binomial(x, k + 1) = binomial(x, k) * (x - k) / (k + 1),
+        // where the division is exact
+        let mut binomial = BigInt::one();
+        for (k, difference) in self.differences.iter().enumerate() {
+            value += &(difference * &binomial);
+            let k = BigInt::from(k);
+            binomial = binomial * (&x - &k) / (k + BigInt::one());
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fit(values: &[i64]) -> Result<Polynomial, FitError> {
+        Polynomial::fit(&values.iter().map(|v| BigInt::from(*v)).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_extrapolate_both_directions() {
+        // n² + 1
+        let polynomial = fit(&[1, 2, 5, 10, 17]).unwrap();
+        assert_eq!(polynomial.degree(), Some(2));
+        assert_eq!(polynomial.at(5), BigInt::from(26));
+        assert_eq!(polynomial.at(-3), BigInt::from(10));
+        assert_eq!(
+            polynomial.at(1_000_000_000_000),
+            "1000000000000000000000001".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_degenerate_sequences() {
+        assert_eq!(fit(&[0, 0]).unwrap().degree(), None);
+        assert_eq!(fit(&[7]).unwrap_err(), FitError::NoZeroLayer { len: 1 });
+        assert_eq!(fit(&[7, 7]).unwrap().at(-5), BigInt::from(7));
+        // could be n³, but three values are not enough to tell
+        assert_eq!(
+            fit(&[0, 1, 8]).unwrap_err(),
+            FitError::NoZeroLayer { len: 3 }
+        );
+    }
+}
@@ -0,0 +1,445 @@
+use std::fmt::Write;
+
+use crate::{
+    diag,
+    error::ParseError,
+    search,
+    solution::{Part, Solution, Style},
+    util::{Connects, Direction, Grid2d, Index2d},
+};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Tile {
+    UpDown,
+    LeftRight,
+    UpRight,
+    UpLeft,
+    DownRight,
+    DownLeft,
+    Ground,
+    Start,
+}
+
+impl Tile {
+    const PIPES: [Tile; 6] = [
+        Tile::UpDown,
+        Tile::LeftRight,
+        Tile::UpRight,
+        Tile::UpLeft,
+        Tile::DownRight,
+        Tile::DownLeft,
+    ];
+
+    /// The two directions a pipe leads to; `None` for tiles that aren't pipes.
+    fn pipe_directions(self) -> Option<[Direction; 2]> {
+        match self {
+            Tile::UpDown => Some([Direction::Up, Direction::Down]),
+            Tile::LeftRight => Some([Direction::Left, Direction::Right]),
+            Tile::UpRight => Some([Direction::Up, Direction::Right]),
+            Tile::UpLeft => Some([Direction::Up, Direction::Left]),
+            Tile::DownRight => Some([Direction::Down, Direction::Right]),
+            Tile::DownLeft => Some([Direction::Down, Direction::Left]),
+            Tile::Ground | Tile::Start => None,
+        }
+    }
+
+    /// Box-drawing character for the tile, with double lines for pipes of the main loop.
+    fn to_box_char(self, on_loop: bool) -> char {
+        match (self, on_loop) {
+            (Tile::UpDown, true) => '║',
+            (Tile::LeftRight, true) => '═',
+            (Tile::UpRight, true) => '╚',
+            (Tile::UpLeft, true) => '╝',
+            (Tile::DownRight, true) => '╔',
+            (Tile::DownLeft, true) => '╗',
+            (Tile::UpDown, false) => '│',
+            (Tile::LeftRight, false) => '─',
+            (Tile::UpRight, false) => '└',
+            (Tile::UpLeft, false) => '┘',
+            (Tile::DownRight, false) => '┌',
+            (Tile::DownLeft, false) => '┐',
+            (Tile::Ground, _) => '.',
+            (Tile::Start, _) => 'S',
+        }
+    }
+
+    /// The pipe leading in directions `a` and `b`, if there is one.
+    fn from_directions(a: Direction, b: Direction) -> Option<Self> {
+        Self::PIPES
+            .into_iter()
+            .find(|pipe| a != b && pipe.connects(a) && pipe.connects(b))
+    }
+}
+
+impl Connects for Tile {
+    /// The start tile could be any pipe, so it connects everywhere.
+    fn connects(&self, direction: Direction) -> bool {
+        match self {
+            Tile::Start => true,
+            tile => tile
+                .pipe_directions()
+                .is_some_and(|directions| directions.contains(&direction)),
+        }
+    }
+}
+
+fn char_to_tile(c: char) -> Result<Tile, String> {
+    let tile = match c {
+        '|' => Tile::UpDown,
+        '-' => Tile::LeftRight,
+        'L' => Tile::UpRight,
+        'J' => Tile::UpLeft,
+        '7' => Tile::DownLeft,
+        'F' => Tile::DownRight,
+        '.' => Tile::Ground,
+        'S' => Tile::Start,
+        _ => return Err(format!("unknown tile `{c}`")),
+    };
+    Ok(tile)
+}
+
+#[derive(Debug)]
+struct Loop {
+    /// The loop's tiles in order, starting with the start tile.
+    path: Vec<Index2d>,
+    on_loop: Grid2d<bool>,
+    start_tile: Tile,
+}
+
+/// Follows the pipes from `start`, leaving it in `first_direction`, until the path gets back to `start`.
+/// `None` if the path hits a dead end instead.
+fn find_loop(grid: &Grid2d<Tile>, start: Index2d, first_direction: Direction) -> Option<Loop> {
+    let mut path = vec![start];
+    let mut direction = first_direction;
+    if !grid.is_linked(start, direction) {
+        return None;
+    }
+    let mut current = start + direction.to_index();
+
+    while current != start {
+        path.push(current);
+        let came_from = direction.invert();
+        direction = grid.linked_directions(current).find(|d| *d != came_from)?;
+        current += direction.to_index();
+    }
+
+    // the path entered the start tile moving in `direction`
+    let start_tile = Tile::from_directions(first_direction, direction.invert())?;
+
+    let mut on_loop = Grid2d::new(grid.len_x(), grid.len_y());
+    for tile in &path {
+        on_loop[*tile] = true;
+    }
+
+    Some(Loop {
+        path,
+        on_loop,
+        start_tile,
+    })
+}
+
+/// Every loop through the start tile, longest first.
+/// The start tile can connect to more than two pipes, so there can be several loops, each making it a different pipe.
+fn find_loops(grid: &Grid2d<Tile>, start: Index2d) -> Vec<Loop> {
+    let mut loops: Vec<Loop> = vec![];
+    for direction in Direction::ALL {
+        let Some(found_loop) = find_loop(grid, start, direction) else {
+            continue;
+        };
+        // every loop is found twice, once in each direction
+        if loops.iter().all(|l| l.start_tile != found_loop.start_tile) {
+            loops.push(found_loop);
+        }
+    }
+
+    loops.sort_by_key(|l| std::cmp::Reverse(l.path.len()));
+    loops
+}
+
+/// Finds the tiles enclosed by the loop by flooding the corners between tiles from the outside.
+/// Corner `(x, y)` is the top left corner of tile `(x, y)`; two neighboring corners are connected
+/// unless a pipe of the loop runs between them.
+fn enclosed_tiles(pipes: &Grid2d<Tile>, on_loop: &Grid2d<bool>) -> Grid2d<bool> {
+    let len_x = pipes.len_x() as i32 + 1;
+    let len_y = pipes.len_y() as i32 + 1;
+    let is_inner_corner = |corner: Index2d| {
+        corner.x > 0 && corner.y > 0 && corner.x < len_x - 1 && corner.y < len_y - 1
+    };
+    let loop_connects =
+        |tile: Index2d, direction: Direction| on_loop[tile] && pipes[tile].connects(direction);
+
+    // the outermost corners are connected to the outside
+    let outermost_corners = (0..len_x)
+        .flat_map(|x| [Index2d { x, y: 0 }, Index2d { x, y: len_y - 1 }])
+        .chain((0..len_y).flat_map(|y| [Index2d { x: 0, y }, Index2d { x: len_x - 1, y }]));
+
+    // starting from the outermost corners, squeeze between pipes to find all corners connected to the outside
+    let connected_to_outside = search::reachable(outermost_corners, |corner| {
+        let corner = *corner;
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| corner + direction.to_index())
+            .filter(move |neighbor| {
+                if !is_inner_corner(*neighbor) {
+                    // outside of corner grid, or an outermost corner, which is connected anyway
+                    return false;
+                }
+
+                // only inner corners are reached from other corners,
+                // so the tiles on both sides of the way between them are guaranteed to exist
+
+                let blocked = if corner.x == neighbor.x {
+                    // vertical line, check for horizontal pipe to block the connection
+                    let y = corner.y.min(neighbor.y);
+                    loop_connects(Index2d { x: corner.x - 1, y }, Direction::Right)
+                        && loop_connects(Index2d { x: corner.x, y }, Direction::Left)
+                } else {
+                    // horizontal line, check for vertical pipe to block the connection
+                    let x = corner.x.min(neighbor.x);
+                    loop_connects(Index2d { x, y: corner.y - 1 }, Direction::Down)
+                        && loop_connects(Index2d { x, y: corner.y }, Direction::Up)
+                };
+                !blocked
+            })
+    });
+
+    // tiles without corners that have been connected are enclosed
+    let mut enclosed = Grid2d::new(pipes.len_x(), pipes.len_y());
+    for tile in pipes.indices() {
+        enclosed[tile] = [
+            Index2d { x: 0, y: 0 },
+            Index2d { x: 1, y: 0 },
+            Index2d { x: 0, y: 1 },
+            Index2d { x: 1, y: 1 },
+        ]
+        .into_iter()
+        .all(|offset| !connected_to_outside.contains(&(tile + offset)));
+    }
+    enclosed
+}
+
+/// Counts the enclosed tiles from the area of the polygon through the centers of the loop's tiles.
+/// All its corners are on tile centers, the loop's tiles lie on its boundary and the enclosed tiles
+/// strictly inside, so Pick's theorem `area = inside + boundary / 2 - 1` gives the number of enclosed tiles.
+fn count_enclosed_tiles_shoelace(path: &[Index2d]) -> usize {
+    // shoelace formula
+    let twice_area = path
+        .iter()
+        .zip(path.iter().cycle().skip(1))
+        .map(|(a, b)| i64::from(a.x) * i64::from(b.y) - i64::from(b.x) * i64::from(a.y))
+        .sum::<i64>()
+        .unsigned_abs() as usize;
+
+    (twice_area + 2 - path.len()) / 2
+}
+
+/// How the tiles enclosed by the loop are counted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Engine {
+    /// Flood the gaps between tiles from the outside and count the tiles that weren't reached.
+    FloodFill,
+    /// Use the shoelace formula and Pick's theorem on the loop as a polygon.
+    Shoelace,
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    grid: Grid2d<Tile>,
+    start: Index2d,
+    /// The longest loop through the start tile.
+    main_loop: Loop,
+}
+
+impl Maze {
+    /// The grid with the start tile replaced by the pipe it has to be for the main loop.
+    fn resolved_pipes(&self) -> Grid2d<Tile> {
+        let mut pipes = self.grid.clone();
+        pipes[self.start] = self.main_loop.start_tile;
+        pipes
+    }
+
+    pub fn count_enclosed_tiles(&self, engine: Engine) -> usize {
+        match engine {
+            Engine::FloodFill => enclosed_tiles(&self.resolved_pipes(), &self.main_loop.on_loop)
+                .iter_indexed()
+                .filter(|(_, enclosed)| **enclosed)
+                .count(),
+            Engine::Shoelace => count_enclosed_tiles_shoelace(&self.main_loop.path),
+        }
+    }
+
+    /// Draws the main loop with double lines and all other pipes with thin ones.
+    /// In plain style, enclosed tiles are drawn as `I`; with ANSI colors, they are highlighted
+    /// instead and the tiles outside the loop are dimmed.
+    pub fn render(&self, style: Style) -> String {
+        const BOLD: &str = "\x1b[1m";
+        const DIM: &str = "\x1b[2m";
+        const ENCLOSED: &str = "\x1b[30;42m";
+        const RESET: &str = "\x1b[0m";
+
+        let pipes = self.resolved_pipes();
+        let on_loop = &self.main_loop.on_loop;
+        let enclosed = enclosed_tiles(&pipes, on_loop);
+
+        let mut rendered = String::new();
+        for y in 0..pipes.len_y() as i32 {
+            if y > 0 {
+                rendered.push('\n');
+            }
+            for x in 0..pipes.len_x() as i32 {
+                let tile = Index2d { x, y };
+                let on_loop = on_loop[tile];
+                let c = pipes[tile].to_box_char(on_loop);
+                match (style, on_loop, enclosed[tile]) {
+                    (Style::Plain, false, true) => rendered.push('I'),
+                    (Style::Plain, _, _) => rendered.push(c),
+                    (Style::Ansi, true, _) => write!(rendered, "{BOLD}{c}{RESET}").unwrap(),
+                    (Style::Ansi, false, true) => write!(rendered, "{ENCLOSED}{c}{RESET}").unwrap(),
+                    (Style::Ansi, false, false) => write!(rendered, "{DIM}{c}{RESET}").unwrap(),
+                }
+            }
+        }
+        rendered
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Maze;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid2d::parse(input, char_to_tile)?;
+
+        let starts = grid
+            .iter_indexed()
+            .filter(|(_, tile)| **tile == Tile::Start)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        let start = *starts
+            .first()
+            .ok_or_else(|| ParseError::new(0, None, "missing start tile `S`"))?;
+        if let Some(other) = starts.get(1) {
+            return Err(ParseError::new(
+                other.y as usize,
+                Some(other.x as usize),
+                "more than one start tile",
+            ));
+        }
+
+        diag!("start position: {start:?}");
+
+        let loops = find_loops(&grid, start);
+        for found_loop in &loops {
+            diag!(
+                "loop of length {} with start tile {:?}",
+                found_loop.path.len(),
+                found_loop.start_tile
+            );
+        }
+        if loops.len() > 1 {
+            diag!("start tile is ambiguous, using the longest loop");
+        }
+        let main_loop = loops.into_iter().next().ok_or_else(|| {
+            ParseError::new(
+                start.y as usize,
+                Some(start.x as usize),
+                "no loop of pipes passes through the start tile",
+            )
+        })?;
+
+        Ok(Maze {
+            grid,
+            start,
+            main_loop,
+        })
+    }
+
+    fn part1(maze: &Self::Input) -> usize {
+        maze.main_loop.path.len() / 2
+    }
+
+    fn part2(maze: &Self::Input) -> usize {
+        maze.count_enclosed_tiles(Engine::Shoelace)
+    }
+
+    /// Both parts show the loop together with the enclosed tiles.
+    fn render(maze: &Self::Input, _part: Part, style: Style) -> Option<String> {
+        Some(maze.render(style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_engines_agree() {
+        let mazes = [
+            include_str!("../../tests/examples/day10a.txt"),
+            include_str!("../../tests/examples/day10b.txt"),
+            include_str!("../../tests/examples/day10c.txt"),
+            include_str!("../../tests/examples/day10d.txt"),
+            // the loop dents inwards from the top
+            "F-7F7\n|.|||\n|.LJ|\nS---J",
+            "S7\nLJ",
+        ];
+        for maze in mazes {
+            let maze = Day10::parse(maze.trim_end()).unwrap();
+            assert_eq!(
+                maze.count_enclosed_tiles(Engine::FloodFill),
+                maze.count_enclosed_tiles(Engine::Shoelace),
+                "{maze:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let maze = Day10::parse("F-7F7\n|.|||\n|.LJ|\nS---J\n.F-7.").unwrap();
+        assert_eq!(
+            maze.render(Style::Plain),
+            "╔═╗╔╗\n║I║║║\n║I╚╝║\n╚═══╝\n.┌─┐."
+        );
+
+        let colored = maze.render(Style::Ansi);
+        assert!(colored.starts_with("\x1b[1m╔\x1b[0m"));
+        assert!(colored.contains("\x1b[30;42m.\x1b[0m"));
+        assert!(colored.contains("\x1b[2m┌\x1b[0m"));
+    }
+
+    #[test]
+    fn test_ambiguous_start() {
+        // the start tile connects to four pipes: it closes a loop up and to the left,
+        // and a longer one down and to the right
+        let maze = Day10::parse("F-7...\n|.|...\nL-S--7\n..|..|\n..L--J").unwrap();
+        assert_eq!(maze.main_loop.start_tile, Tile::DownRight);
+        assert_eq!(Day10::part1(&maze), 5);
+        assert_eq!(Day10::part2(&maze), 2);
+        assert_eq!(find_loops(&maze.grid, maze.start).len(), 2);
+    }
+
+    #[test]
+    fn test_no_loop() {
+        let error = Day10::parse("..F7\n.S-J\n....").unwrap_err();
+        assert_eq!(error.line(), 1);
+        assert_eq!(error.column(), Some(1));
+        assert!(error.message().contains("no loop"));
+
+        let error = Day10::parse("F7\nLJ").unwrap_err();
+        assert!(error.message().contains("missing start tile"));
+    }
+
+    #[test]
+    fn test_tile_connections() {
+        for pipe in Tile::PIPES {
+            let [a, b] = pipe.pipe_directions().unwrap();
+            assert_eq!(Tile::from_directions(a, b), Some(pipe));
+            assert_eq!(Tile::from_directions(b, a), Some(pipe));
+        }
+        assert_eq!(Tile::from_directions(Direction::Up, Direction::Up), None);
+        assert!(!Tile::Ground.connects(Direction::Up));
+    }
+}
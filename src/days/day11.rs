@@ -0,0 +1,112 @@
+use crate::{error::ParseError, solution::Solution, util::Grid2d};
+
+const PART_1_OFFSET: usize = 1;
+const PART_2_OFFSET: usize = 999999;
+
+fn empty_row_offsets(grid: &Grid2d<char>, offset: usize) -> Vec<usize> {
+    let mut ret = vec![];
+
+    let mut offset_so_far: usize = 0;
+
+    for row in grid.rows() {
+        if row.iter().all(|c| *c == '.') {
+            offset_so_far = offset_so_far
+                .checked_add(offset)
+                .expect("expansion overflows usize");
+        }
+        ret.push(offset_so_far);
+    }
+
+    ret
+}
+
+fn empty_col_offsets(grid: &Grid2d<char>, offset: usize) -> Vec<usize> {
+    let mut ret = vec![];
+
+    let mut offset_so_far: usize = 0;
+
+    for mut column in grid.columns() {
+        if column.all(|c| *c == '.') {
+            offset_so_far = offset_so_far
+                .checked_add(offset)
+                .expect("expansion overflows usize");
+        }
+        ret.push(offset_so_far);
+    }
+
+    ret
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid2d<char>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid2d::parse(input, |c| match c {
+            '.' | '#' => Ok(c),
+            _ => Err(format!("unknown tile `{c}`")),
+        })?;
+
+        if grid.len_y() == 0 {
+            return Err(ParseError::new(0, None, "empty input"));
+        }
+
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> u64 {
+        expanded_distance_sum(grid, PART_1_OFFSET)
+    }
+
+    fn part2(grid: &Self::Input) -> u64 {
+        expanded_distance_sum(grid, PART_2_OFFSET)
+    }
+}
+
+fn expanded_distance_sum(grid: &Grid2d<char>, offset: usize) -> u64 {
+    let empty_rows = empty_row_offsets(grid, offset);
+    let empty_cols = empty_col_offsets(grid, offset);
+
+    let galaxy_locations = find_galaxy_locations(grid);
+
+    all_pairs_shortest_path_sum(galaxy_locations, empty_rows, empty_cols)
+}
+
+fn all_pairs_shortest_path_sum(
+    galaxy_locations: Vec<(usize, usize)>,
+    empty_rows: Vec<usize>,
+    empty_cols: Vec<usize>,
+) -> u64 {
+    // with large expansion factors, the coordinates and the sum can get big;
+    // rather fail loudly than return a wrapped answer.
+    let expanded = |index: usize, offsets: &[usize]| {
+        (index as u64)
+            .checked_add(offsets[index] as u64)
+            .expect("expanded coordinate overflows u64")
+    };
+
+    let mut total: u64 = 0;
+    for (i, (a, b)) in galaxy_locations.iter().cloned().enumerate() {
+        for (c, d) in galaxy_locations[i + 1..].iter().cloned() {
+            let row_diff = expanded(a, &empty_rows).abs_diff(expanded(c, &empty_rows));
+            let col_diff = expanded(b, &empty_cols).abs_diff(expanded(d, &empty_cols));
+
+            total = row_diff
+                .checked_add(col_diff)
+                .and_then(|distance| total.checked_add(distance))
+                .expect("distance sum overflows u64");
+        }
+    }
+
+    total
+}
+
+fn find_galaxy_locations(grid: &Grid2d<char>) -> Vec<(usize, usize)> {
+    grid.iter_indexed()
+        .filter(|(_, c)| **c == '#')
+        .map(|(index, _)| (index.y as usize, index.x as usize))
+        .collect()
+}
@@ -1,4 +1,8 @@
-use aoc2023::util::{Grid2d, Index2d};
+use crate::{
+    error::{self, Line, ParseError},
+    solution::Solution,
+    util::{Grid2d, Index2d},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SpringStatus {
@@ -8,39 +12,47 @@ enum SpringStatus {
     Unknown,
 }
 
-#[derive(Debug)]
-struct Record {
+#[derive(Debug, Clone)]
+pub struct Record {
     status_data: Vec<SpringStatus>,
     damaged_groups: Vec<usize>,
 }
 
 impl Record {
-    fn from_line(line: &str) -> Self {
-        let mut it = line.split_whitespace();
-        let status_data = it
-            .next()
-            .unwrap()
-            .chars()
-            .map(|c| match c {
-                '.' => SpringStatus::Operational,
-                '#' => SpringStatus::Damaged,
-                '?' => SpringStatus::Unknown,
-                _ => panic!("Can't handle spring status {c}"),
+    pub fn from_line(line: Line) -> Result<Self, ParseError> {
+        let mut it = line.text().split_whitespace();
+        let (Some(springs), Some(nums)) = (it.next(), it.next()) else {
+            return Err(line.error("expected `<springs> <damaged groups>`"));
+        };
+
+        let status_data = springs
+            .char_indices()
+            .map(|(i, c)| match c {
+                '.' => Ok(SpringStatus::Operational),
+                '#' => Ok(SpringStatus::Damaged),
+                '?' => Ok(SpringStatus::Unknown),
+                _ => Err(line.error_at(&springs[i..], format!("unknown spring status `{c}`"))),
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        let nums = it.next().unwrap();
-        let damaged_groups = nums.split(',').map(|s| s.parse().unwrap()).collect();
+        let damaged_groups = nums
+            .split(',')
+            .map(|s| match line.parse(s, "group size")? {
+                0 => Err(line.error_at(s, "groups can't be empty")),
+                size => Ok(size),
+            })
+            .collect::<Result<_, _>>()?;
 
-        Self {
+        Ok(Self {
             status_data,
             damaged_groups,
-        }
+        })
     }
 
-    fn ways_to_match(&self) -> usize {
+    pub fn ways_to_match(&self) -> u128 {
         // We don't have to compute or save the resulting spring status vec.
-        let mut dp_table = Grid2d::new(self.damaged_groups.len(), self.status_data.len());
+        let mut dp_table: Grid2d<u128> =
+            Grid2d::new(self.damaged_groups.len(), self.status_data.len());
 
         // start with the last group and iterate back to the first
         for group_index in (0..self.damaged_groups.len()).rev() {
@@ -56,10 +68,7 @@ impl Record {
                     // group would go out of bounds
                     continue;
                 }
-                if self.status_data[start_index..end_index]
-                    .iter()
-                    .any(|s| *s == SpringStatus::Operational)
-                {
+                if self.status_data[start_index..end_index].contains(&SpringStatus::Operational) {
                     // can't assign the group here.
                     continue;
                 }
@@ -68,10 +77,7 @@ impl Record {
                 if last_group {
                     // make sure that all remaining blocks are either operational or unknown; if they are, save 1, otherwise 0.
                     // note that since this is the last group, the unknown springs have to be operational
-                    if self.status_data[end_index..]
-                        .iter()
-                        .any(|s| *s == SpringStatus::Damaged)
-                    {
+                    if self.status_data[end_index..].contains(&SpringStatus::Damaged) {
                         // nope
                         continue;
                     }
@@ -90,10 +96,7 @@ impl Record {
                     if group_index == 0 {
                         // We've arrived at the first group.
                         // If there are any damaged springs before the current start index, the current combination is invalid.
-                        if self.status_data[0..start_index]
-                            .iter()
-                            .any(|s| *s == SpringStatus::Damaged)
-                        {
+                        if self.status_data[0..start_index].contains(&SpringStatus::Damaged) {
                             continue;
                         }
                     }
@@ -113,16 +116,17 @@ impl Record {
                         // otherwise, the resulting combination of groups would have extra damaged springs that would be unaccounted for.
                         if self.status_data
                             [next_group_first_potential_start..next_group_actual_start]
-                            .iter()
-                            .any(|s| *s == SpringStatus::Damaged)
+                            .contains(&SpringStatus::Damaged)
                         {
                             continue;
                         }
 
-                        total += dp_table[Index2d {
+                        total = dp_table[Index2d {
                             x: next_group_idx as _,
                             y: next_group_actual_start as _,
-                        }];
+                        }]
+                        .checked_add(total)
+                        .expect("number of arrangements overflows u128");
                     }
                     dp_table[Index2d {
                         x: group_index as _,
@@ -132,21 +136,25 @@ impl Record {
             }
         }
 
-        let mut total = 0;
+        let mut total: u128 = 0;
         // for the total, check the number of possible assignments for the first group and add them up.
 
         let first_group_idx = 0;
         for i in 0..self.status_data.len() {
-            total += dp_table[Index2d {
-                x: first_group_idx,
-                y: i as _,
-            }];
+            total = total
+                .checked_add(
+                    dp_table[Index2d {
+                        x: first_group_idx,
+                        y: i as _,
+                    }],
+                )
+                .expect("number of arrangements overflows u128");
         }
 
         total
     }
 
-    fn expand(&mut self) {
+    pub fn expand(&mut self) {
         self.damaged_groups = self
             .damaged_groups
             .iter()
@@ -165,18 +173,44 @@ impl Record {
     }
 }
 
-fn main() {
-    let input = std::fs::read_to_string("input/12.txt").unwrap();
-    let part2 = true;
-    let mut total = 0;
-    for line in input.lines() {
-        let mut record = Record::from_line(line);
-        if part2 {
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        error::lines(input).map(Record::from_line).collect()
+    }
+
+    fn part1(records: &Self::Input) -> u128 {
+        sum_ways_to_match(records.iter().map(Record::ways_to_match))
+    }
+
+    fn part2(records: &Self::Input) -> u128 {
+        sum_ways_to_match(records.iter().map(|record| {
+            let mut record = record.clone();
             record.expand();
-        }
-        let ways_to_match = record.ways_to_match();
-        total += ways_to_match;
+            record.ways_to_match()
+        }))
     }
+}
 
-    println!("total ways to match: {total}");
+fn sum_ways_to_match(mut ways: impl Iterator<Item = u128>) -> u128 {
+    ways.try_fold(0u128, u128::checked_add)
+        .expect("total number of arrangements overflows u128")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_many_arrangements() {
+        // expanded, this is 30 groups of one in 99 unknown springs: binomial(70, 30) arrangements,
+        // which doesn't fit into a u64
+        let records = Day12::parse("??????????????????? 1,1,1,1,1,1").unwrap();
+        assert_eq!(Day12::part2(&records), 55_347_740_058_143_507_128);
+    }
 }
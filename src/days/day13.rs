@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use crate::{
+    diag,
+    error::{self, Line, ParseError},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum MirrorType {
+    Vertical,
+    Horizontal,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Mirror {
+    mirror_type: MirrorType,
+    after: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct Pattern {
+    rows: Vec<String>,
+    columns: Vec<String>,
+}
+
+impl Pattern {
+    pub fn push_line(&mut self, s: &str) {
+        self.push_line_owned(s.to_owned())
+    }
+
+    pub fn push_line_owned(&mut self, s: String) {
+        self.rows.push(s);
+    }
+
+    pub fn finalize(&mut self) {
+        let len = self.rows[0].len();
+        for i in 0..len {
+            let mut s = String::with_capacity(len);
+            for line in &self.rows {
+                s += &line[i..i + 1];
+            }
+            self.columns.push(s);
+        }
+    }
+
+    pub fn find_mirror(&self, part2: bool) -> Option<Mirror> {
+        for (strings, mirror, num_strings) in [
+            (&self.rows, MirrorType::Horizontal, self.rows.len()),
+            (&self.columns, MirrorType::Vertical, self.columns.len()),
+        ] {
+            if let Some(mirror) = find_mirror_for_strings(mirror, num_strings, part2, strings) {
+                return Some(mirror);
+            }
+        }
+
+        None
+    }
+}
+
+fn find_mirror_for_strings(
+    mirror_type: MirrorType,
+    num_strings: usize,
+    part2: bool,
+    strings: &[String],
+) -> Option<Mirror> {
+    let mut map = HashMap::new();
+    for (i, line) in strings.iter().enumerate() {
+        map.entry(line.to_owned()).or_insert(vec![]).push(i);
+    }
+
+    let mut inverse_map = HashMap::new();
+    for list in map.values() {
+        for i in list {
+            inverse_map.insert(*i, list[0]);
+        }
+    }
+
+    let mut mirror_pos = None;
+
+    for i in 0..num_strings - 1 {
+        // check if i is a mirror point
+
+        let mut left = i as i32;
+
+        let mut right = i + 1;
+        let mut mismatches = vec![];
+
+        while left >= 0 && right < num_strings {
+            if inverse_map[&(left as usize)] != inverse_map[&right] {
+                // not mirrored
+                mismatches.push((left, right));
+
+                if !part2 || mismatches.len() > 1 {
+                    // part 1 allows no mismatches, part2 only one
+                    break;
+                }
+            }
+            left -= 1;
+            right += 1;
+        }
+
+        let mirrored = if part2 {
+            match &mismatches[..] {
+                // for part 2, mirroring requires exactly one smidge:
+                // exactly one position must differ for one pair of strings.
+                &[(left, right)] => {
+                    let a = &strings[left as usize];
+                    let b = &strings[right];
+                    let num_diff = a.chars().zip(b.chars()).filter(|(a, b)| a != b).count();
+                    num_diff == 1
+                }
+                _ => false,
+            }
+        } else {
+            mismatches.is_empty()
+        };
+
+        if mirrored {
+            mirror_pos = Some(i);
+        }
+    }
+
+    mirror_pos.map(|pos| Mirror {
+        mirror_type,
+        after: pos,
+    })
+}
+
+/// The mirrors of a pattern, for both parts.
+#[derive(Debug, Clone, Copy)]
+pub struct Mirrors {
+    part1: Mirror,
+    part2: Mirror,
+}
+
+/// Finds the mirrors of the pattern that starts at `start`, failing if a part has none.
+fn find_mirrors(mut pattern: Pattern, start: Line) -> Result<Mirrors, ParseError> {
+    pattern.finalize();
+    Ok(Mirrors {
+        part1: pattern
+            .find_mirror(false)
+            .ok_or_else(|| start.error("pattern has no mirror"))?,
+        part2: pattern
+            .find_mirror(true)
+            .ok_or_else(|| start.error("pattern has no mirror with exactly one smudge"))?,
+    })
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Mirrors>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut patterns = vec![];
+        let mut pattern = Pattern::default();
+        // the first line of `pattern`
+        let mut start = None;
+
+        for line in error::lines(input) {
+            if line.text().chars().all(|c| c.is_whitespace()) {
+                if let Some(start) = start.take() {
+                    patterns.push(find_mirrors(std::mem::take(&mut pattern), start)?);
+                }
+                continue;
+            }
+
+            if let Some((x, c)) = line
+                .text()
+                .char_indices()
+                .find(|(_, c)| *c != '.' && *c != '#')
+            {
+                return Err(line.error_at_column(x, format!("unknown tile `{c}`")));
+            }
+            if let Some(first_row) = pattern.rows.first() {
+                if line.text().len() != first_row.len() {
+                    return Err(line.error(format!(
+                        "expected a row of length {}, found length {}",
+                        first_row.len(),
+                        line.text().len()
+                    )));
+                }
+            }
+            start.get_or_insert(line);
+            pattern.push_line(line.text());
+        }
+
+        if let Some(start) = start {
+            patterns.push(find_mirrors(pattern, start)?);
+        }
+
+        Ok(patterns)
+    }
+
+    fn part1(patterns: &Self::Input) -> usize {
+        summarize(patterns, |mirrors| mirrors.part1)
+    }
+
+    fn part2(patterns: &Self::Input) -> usize {
+        summarize(patterns, |mirrors| mirrors.part2)
+    }
+}
+
+fn summarize(patterns: &[Mirrors], mirror: impl Fn(&Mirrors) -> Mirror) -> usize {
+    let mut total = 0;
+
+    for mirrors in patterns {
+        let mirror = mirror(mirrors);
+        diag!("found mirror: {mirror:?}");
+
+        let summary = match mirror.mirror_type {
+            MirrorType::Vertical => Some(mirror.after + 1),
+            MirrorType::Horizontal => (mirror.after + 1).checked_mul(100),
+        };
+        total = summary
+            .and_then(|summary| summary.checked_add(total))
+            .expect("summary overflows usize");
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reject_pattern_without_mirror() {
+        let error = Day13::parse("..\n..\n.#\n\n#.\n.#\n").unwrap_err();
+        assert_eq!(error.line(), 4);
+        assert_eq!(error.message(), "pattern has no mirror");
+
+        // mirrored without a smudge, but no way to fix one
+        let error = Day13::parse("#.\n#.").unwrap_err();
+        assert_eq!(error.line(), 0);
+        assert_eq!(
+            error.message(),
+            "pattern has no mirror with exactly one smudge"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{
+    cycle, diag,
+    error::ParseError,
+    solution::Solution,
+    util::{Grid2d, Index2d, ToChar},
+};
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Tile {
+    #[default]
+    Space,
+    Loose,
+    Fixed,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Result<Self, String> {
+        match c {
+            '.' => Ok(Self::Space),
+            'O' => Ok(Self::Loose),
+            '#' => Ok(Self::Fixed),
+            _ => Err(format!("unknown tile character `{c}`")),
+        }
+    }
+}
+
+impl ToChar for Tile {
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Space => '.',
+            Tile::Loose => 'O',
+            Tile::Fixed => '#',
+        }
+    }
+}
+
+fn shift_grid(grid: &mut Grid2d<Tile>, start_tiles: &[Index2d], direction: Index2d) {
+    for tile in start_tiles {
+        let mut first_free_index = *tile;
+        let mut current = *tile;
+
+        while grid.is_valid(current) {
+            //
+            match grid[current] {
+                Tile::Space => {}
+                Tile::Loose => {
+                    if first_free_index != current {
+                        grid[first_free_index] = Tile::Loose;
+                        grid[current] = Tile::Space;
+                    }
+                    first_free_index += direction;
+                }
+                Tile::Fixed => {
+                    first_free_index = current + direction;
+                }
+            }
+
+            current += direction;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+
+enum Direction {
+    North,
+    West,
+    South,
+    East,
+}
+
+fn shift_grid_in_direction(grid: &mut Grid2d<Tile>, direction: Direction) {
+    let (start_tiles, direction) = match direction {
+        Direction::North => {
+            let top_row = (0..grid.len_x())
+                .map(|i| Index2d { x: i as i32, y: 0 })
+                .collect::<Vec<_>>();
+            (top_row, Index2d { x: 0, y: 1 })
+        }
+        Direction::West => {
+            let left_row = (0..grid.len_y())
+                .map(|i| Index2d { x: 0, y: i as i32 })
+                .collect::<Vec<_>>();
+            (left_row, Index2d { x: 1, y: 0 })
+        }
+        Direction::South => {
+            let bottom_row = (0..grid.len_x())
+                .map(|i| Index2d {
+                    x: i as i32,
+                    y: grid.len_y() as i32 - 1,
+                })
+                .collect::<Vec<_>>();
+            (bottom_row, Index2d { x: 0, y: -1 })
+        }
+        Direction::East => {
+            let right_row = (0..grid.len_y())
+                .map(|i| Index2d {
+                    x: grid.len_x() as i32 - 1,
+                    y: i as i32,
+                })
+                .collect::<Vec<_>>();
+            (right_row, Index2d { x: -1, y: 0 })
+        }
+    };
+
+    shift_grid(grid, &start_tiles, direction);
+}
+
+const NUM_CYCLES: usize = 1000000000;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid2d<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid2d::parse(input, Tile::from_char)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        let mut grid = grid.clone();
+        shift_grid_in_direction(&mut grid, Direction::North);
+        diag!("shifted:\n{grid}");
+
+        total_load(&grid)
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        let history = cycle::detect(grid.clone(), spin_cycle);
+
+        let cycle = history.cycle();
+        diag!(
+            "grid repeats after {} spin cycles, loop length {}",
+            cycle.start,
+            cycle.length
+        );
+
+        total_load(history.state_at(NUM_CYCLES))
+    }
+}
+
+/// Tilts the grid north, then west, then south, then east.
+fn spin_cycle(grid: &Grid2d<Tile>) -> Grid2d<Tile> {
+    let mut grid = grid.clone();
+    for direction in [
+        Direction::North,
+        Direction::West,
+        Direction::South,
+        Direction::East,
+    ] {
+        shift_grid_in_direction(&mut grid, direction);
+    }
+    grid
+}
+
+fn total_load(grid: &Grid2d<Tile>) -> usize {
+    grid.iter_indexed()
+        .filter(|(_, tile)| **tile == Tile::Loose)
+        .map(|(index, _)| grid.len_y() - index.y as usize)
+        .sum()
+}
@@ -0,0 +1,141 @@
+use crate::{
+    error::{Line, ParseError},
+    solution::Solution,
+};
+
+fn hash(s: &str) -> u32 {
+    let mut result = 0;
+
+    for c in s.chars() {
+        result += c as u32;
+        result *= 17;
+        result %= 256;
+    }
+
+    result
+}
+
+const NUM_BOXES: usize = 256;
+
+#[derive(Debug, Clone)]
+pub struct Lense {
+    focal_length: u32,
+    label: String,
+}
+
+#[derive(Debug, Default)]
+struct LenseBox {
+    lenses: Vec<Lense>,
+}
+
+impl LenseBox {
+    fn add(&mut self, lense: Lense) {
+        if let Some(pos) = self.lenses.iter().position(|l| l.label == lense.label) {
+            self.lenses[pos] = lense;
+        } else {
+            self.lenses.push(lense);
+        }
+    }
+
+    fn remove(&mut self, label: &str) {
+        if let Some(pos) = self.lenses.iter().position(|l| l.label == label) {
+            self.lenses.remove(pos);
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Operation {
+    Insert(Lense),
+    Remove(String),
+}
+
+#[derive(Debug)]
+pub struct Step {
+    text: String,
+    operation: Operation,
+}
+
+impl Step {
+    fn parse(line: &Line, text: &str) -> Result<Self, ParseError> {
+        let operation = if let Some((label, focal_length)) = text.split_once('=') {
+            Operation::Insert(Lense {
+                focal_length: line.parse(focal_length, "focal length")?,
+                label: label.to_owned(),
+            })
+        } else if let Some(label) = text.strip_suffix('-') {
+            Operation::Remove(label.to_owned())
+        } else {
+            return Err(line.error_at(text, format!("invalid step `{text}`")));
+        };
+
+        Ok(Self {
+            text: text.to_owned(),
+            operation,
+        })
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    // the comma separated initialization steps
+    type Input = Vec<Step>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut input = input.to_owned();
+        input.retain(|c| c != '\n');
+
+        let line = Line::new(0, &input);
+        input.split(',').map(|s| Step::parse(&line, s)).collect()
+    }
+
+    fn part1(steps: &Self::Input) -> u32 {
+        steps
+            .iter()
+            .try_fold(0u32, |sum, s| sum.checked_add(hash(&s.text)))
+            .expect("sum of hashes overflows u32")
+    }
+
+    fn part2(steps: &Self::Input) -> u64 {
+        let mut boxes = (0..NUM_BOXES)
+            .map(|_| LenseBox::default())
+            .collect::<Vec<_>>();
+
+        for step in steps {
+            match &step.operation {
+                Operation::Insert(lense) => {
+                    let index = hash(&lense.label);
+                    let lense_box = &mut boxes[index as usize];
+                    lense_box.add(lense.clone())
+                }
+                Operation::Remove(label) => {
+                    let index = hash(label);
+                    let lense_box = &mut boxes[index as usize];
+                    lense_box.remove(label);
+                }
+            }
+        }
+
+        let mut total = 0u64;
+        for (i, b) in boxes.iter().enumerate() {
+            let mut lense_power = 0u64;
+            for (lense_idx, lense) in b.lenses.iter().enumerate() {
+                let lense_index_multiplier = lense_idx as u64 + 1;
+                lense_power = lense_index_multiplier
+                    .checked_mul(lense.focal_length as u64)
+                    .and_then(|power| power.checked_add(lense_power))
+                    .expect("focusing power overflows u64");
+            }
+            let index_multiplier = i as u64 + 1;
+            total = lense_power
+                .checked_mul(index_multiplier)
+                .and_then(|power| power.checked_add(total))
+                .expect("total focusing power overflows u64");
+        }
+
+        total
+    }
+}
@@ -1,9 +1,13 @@
 use std::collections::HashSet;
 
-use aoc2023::util::{Direction, Grid2d, Index2d};
+use crate::{
+    error::ParseError,
+    solution::Solution,
+    util::{Direction, Grid2d, Index2d, ToChar},
+};
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
-enum Tile {
+pub enum Tile {
     #[default]
     Space,
     HorizontalSplit,
@@ -12,48 +16,53 @@ enum Tile {
     MirrorRightDown,
 }
 
-fn main() {
-    let input_file = if let Some(file) = std::env::args().nth(1) {
-        file
-    } else {
-        "input/16_training.txt".to_owned()
-    };
-    println!("using input file `{input_file}`");
-    let input = std::fs::read_to_string(input_file).unwrap();
-    println!("{input}");
-
-    let part2 = if let Some(flag) = std::env::args().nth(2) {
-        flag.parse().unwrap()
-    } else {
-        false
-    };
-
-    println!("part2 flag: {part2}");
-
-    let mut grid = Grid2d::new(input.lines().next().unwrap().len(), input.lines().count());
-
-    for (y, line) in input.lines().enumerate() {
-        for (x, c) in line.char_indices() {
-            grid[Index2d {
-                y: y as _,
-                x: x as _,
-            }] = match c {
-                '.' => Tile::Space,
-                '-' => Tile::HorizontalSplit,
-                '|' => Tile::VerticalSplit,
-                '/' => Tile::MirrorRightUp,
-                '\\' => Tile::MirrorRightDown,
-                _ => panic!("unknown tile: {c}"),
-            };
+impl Tile {
+    fn from_char(c: char) -> Result<Self, String> {
+        match c {
+            '.' => Ok(Tile::Space),
+            '-' => Ok(Tile::HorizontalSplit),
+            '|' => Ok(Tile::VerticalSplit),
+            '/' => Ok(Tile::MirrorRightUp),
+            '\\' => Ok(Tile::MirrorRightDown),
+            _ => Err(format!("unknown tile `{c}`")),
         }
     }
+}
+
+impl ToChar for Tile {
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Space => '.',
+            Tile::HorizontalSplit => '-',
+            Tile::VerticalSplit => '|',
+            Tile::MirrorRightUp => '/',
+            Tile::MirrorRightDown => '\\',
+        }
+    }
+}
+
+pub struct Day16;
 
-    println!("reconstructed:");
-    print_grid(&grid);
+impl Solution for Day16 {
+    type Input = Grid2d<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    // follow the lights
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid2d::parse(input, Tile::from_char)?;
+        if grid.len_x() == 0 || grid.len_y() == 0 {
+            return Err(ParseError::new(0, None, "empty grid"));
+        }
+
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        num_energized_tiles(grid, Index2d { x: 0, y: 0 }, Direction::Right)
+    }
 
-    let num_energized_tiles = if part2 {
+    fn part2(grid: &Self::Input) -> usize {
+        // try every edge tile as the starting point of the beam
         (0..grid.len_x())
             .flat_map(|i| {
                 [
@@ -79,13 +88,10 @@ fn main() {
                     ),
                 ]
             }))
-            .map(|(pos, direction)| num_energized_tiles(&grid, pos, direction))
+            .map(|(pos, direction)| num_energized_tiles(grid, pos, direction))
             .max()
-            .unwrap()
-    } else {
-        num_energized_tiles(&grid, Index2d { x: 0, y: 0 }, Direction::Right)
-    };
-    println!("number of energized tiles: {num_energized_tiles}");
+            .expect("the grid is not empty")
+    }
 }
 
 fn num_energized_tiles(
@@ -97,15 +103,6 @@ fn num_energized_tiles(
     let mut ray_start_positions = vec![(start_position, direction)];
     let mut light_map: Grid2d<HashSet<Direction>> = Grid2d::new(grid.len_x(), grid.len_y());
 
-    for y in 0..grid.len_y() {
-        for x in 0..grid.len_x() {
-            light_map[Index2d {
-                x: x as _,
-                y: y as _,
-            }] = HashSet::new();
-        }
-    }
-
     while let Some((mut position, mut direction)) = ray_start_positions.pop() {
         while grid.is_valid(position) {
             if light_map[position].contains(&direction) {
@@ -156,38 +153,19 @@ fn num_energized_tiles(
         }
     }
 
-    let mut num_energized_tiles = 0;
-    for x in 0..grid.len_x() {
-        for y in 0..grid.len_y() {
-            if !light_map[Index2d {
-                x: x as _,
-                y: y as _,
-            }]
-            .is_empty()
-            {
-                num_energized_tiles += 1;
-            }
-        }
-    }
-
-    num_energized_tiles
+    light_map
+        .iter_indexed()
+        .filter(|(_, directions)| !directions.is_empty())
+        .count()
 }
 
-fn print_grid(grid: &Grid2d<Tile>) {
-    for y in 0..grid.len_y() {
-        let mut line = String::new();
-        for x in 0..grid.len_x() {
-            match grid[Index2d {
-                x: x as _,
-                y: y as _,
-            }] {
-                Tile::Space => line += ".",
-                Tile::HorizontalSplit => line += "-",
-                Tile::VerticalSplit => line += "|",
-                Tile::MirrorRightUp => line += "/",
-                Tile::MirrorRightDown => line += "\\",
-            }
-        }
-        println!("{line}");
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reject_empty_grid() {
+        assert_eq!(Day16::parse("").unwrap_err().message(), "empty grid");
+        assert_eq!(Day16::parse("\n\n").unwrap_err().message(), "empty grid");
     }
 }
@@ -0,0 +1,242 @@
+use std::fmt::Display;
+
+use crate::{
+    error::ParseError,
+    search,
+    solution::{Part, Solution, Style},
+    util::{Direction, Grid2d, Index2d, Overlay},
+};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid2d<u32>;
+    type Answer1 = HeatLoss;
+    type Answer2 = HeatLoss;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid2d::parse(input, |n| {
+            n.to_digit(10).ok_or(format!("invalid heat loss `{n}`"))
+        })?;
+
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> HeatLoss {
+        HeatLoss::of(find_route(grid, Part::One))
+    }
+
+    fn part2(grid: &Self::Input) -> HeatLoss {
+        HeatLoss::of(find_route(grid, Part::Two))
+    }
+
+    fn render(grid: &Self::Input, part: Part, _style: Style) -> Option<String> {
+        let rendered = match find_route(grid, part) {
+            Some(route) => format!(
+                "heat loss: {}\n{}",
+                route.heat_loss,
+                render_route(grid, &route)
+            ),
+            None => HeatLoss::NoRoute.to_string(),
+        };
+        Some(rendered)
+    }
+}
+
+/// Answer to both parts: the crucible might not be able to reach the goal at all,
+/// e.g. an ultra crucible on a grid too small to ever move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeatLoss {
+    Minimal(u32),
+    NoRoute,
+}
+
+impl HeatLoss {
+    fn of(route: Option<Route>) -> Self {
+        route.map_or(HeatLoss::NoRoute, |route| {
+            HeatLoss::Minimal(route.heat_loss)
+        })
+    }
+}
+
+impl Display for HeatLoss {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HeatLoss::Minimal(heat_loss) => write!(f, "{heat_loss}"),
+            HeatLoss::NoRoute => write!(f, "no route"),
+        }
+    }
+}
+
+/// A straight part of a route: `length` blocks from `start` in `direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub start: Index2d,
+    pub direction: Direction,
+    pub length: i32,
+}
+
+impl Segment {
+    pub fn end(&self) -> Index2d {
+        self.start + self.direction.to_index() * self.length
+    }
+}
+
+/// The route with minimal heat loss from the top left to the bottom right corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub heat_loss: u32,
+    pub segments: Vec<Segment>,
+}
+
+impl Route {
+    /// Start and end points of all segments, in order.
+    pub fn points(&self) -> Vec<Index2d> {
+        let mut points = vec![Index2d { x: 0, y: 0 }];
+        points.extend(self.segments.iter().map(Segment::end));
+        points
+    }
+}
+
+/// Draws the route on top of the heat loss grid, with arrows showing the direction of movement.
+pub fn render_route(grid: &Grid2d<u32>, route: &Route) -> String {
+    let mut overlay = Overlay::default();
+    overlay.path(&route.points());
+    grid.render_with_overlay(|n| char::from_digit(*n, 10).unwrap_or('?'), &overlay)
+}
+
+/// Part 1 uses regular crucibles, part 2 ultra crucibles.
+/// `None` if the crucible can't reach the bottom right block.
+pub fn find_route(grid: &Grid2d<u32>, part: Part) -> Option<Route> {
+    match part {
+        Part::One => min_heat_loss_route(grid, 1, 3),
+        Part::Two => min_heat_loss_route(grid, 4, 10),
+    }
+}
+
+/// Search state: where the crucible is and in which direction it moved to get there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Crucible {
+    position: Index2d,
+    direction: Direction,
+}
+
+/// Finds the route with minimal heat loss from the top left to the bottom right corner
+/// for a crucible that has to move between `min` and `max` blocks before turning.
+fn min_heat_loss_route(grid: &Grid2d<u32>, min: i32, max: i32) -> Option<Route> {
+    if grid.len_x() == 0 || grid.len_y() == 0 {
+        return None;
+    }
+
+    // we never need to visit the same vertex twice, except if we leave in a new direction.
+    // hence the direction we arrived in is part of the search state.
+    // the crucible can start in either direction, so we pretend it arrived moving left or down.
+    let starts = [Direction::Left, Direction::Down].map(|direction| Crucible {
+        position: Index2d { x: 0, y: 0 },
+        direction,
+    });
+
+    let goal = Index2d {
+        x: grid.len_x() as i32 - 1,
+        y: grid.len_y() as i32 - 1,
+    };
+
+    let successors = |node: &Crucible| {
+        let mut ret = vec![];
+
+        // turn left or right, then move between `min` and `max` blocks.
+        for direction in Direction::ALL {
+            if direction == node.direction || direction == node.direction.invert() {
+                continue;
+            }
+
+            let mut cost = 0;
+
+            let min_position = node.position + direction.to_index() * min;
+            if !grid.is_valid(min_position) {
+                continue;
+            }
+
+            for i in 1..min {
+                // the cost for the offset times min will be added in the first iteration of the loop below.
+                // this cost addition is only for the cost before another decision point is reached.
+                cost += grid[node.position + direction.to_index() * i];
+            }
+
+            for i in min..=max {
+                let neighbor = node.position + direction.to_index() * i;
+                if !grid.is_valid(neighbor) {
+                    break;
+                }
+                cost += grid[neighbor];
+                ret.push((
+                    Crucible {
+                        position: neighbor,
+                        direction,
+                    },
+                    cost,
+                ));
+            }
+        }
+
+        ret
+    };
+
+    let result = search::dijkstra(starts, successors, |node| node.position == goal)?;
+
+    // every search step is one straight segment
+    let segments = result
+        .path
+        .windows(2)
+        .map(|step| {
+            let (from, to) = (step[0].position, step[1].position);
+            Segment {
+                start: from,
+                direction: step[1].direction,
+                length: (to.x - from.x).abs() + (to.y - from.y).abs(),
+            }
+        })
+        .collect();
+
+    Some(Route {
+        heat_loss: result.cost,
+        segments,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_route_matches_heat_loss() {
+        let grid = Day17::parse("24134\n32154\n32552\n34465\n45463").unwrap();
+        for part in [Part::One, Part::Two] {
+            let route = find_route(&grid, part).unwrap();
+
+            let mut position = Index2d { x: 0, y: 0 };
+            let mut heat_loss = 0;
+            for segment in &route.segments {
+                assert_eq!(segment.start, position);
+                for _ in 0..segment.length {
+                    position += segment.direction.to_index();
+                    heat_loss += grid[position];
+                }
+            }
+
+            assert_eq!(position, Index2d { x: 4, y: 4 });
+            assert_eq!(heat_loss, route.heat_loss);
+        }
+    }
+
+    #[test]
+    fn test_no_route() {
+        // an ultra crucible has to move at least four blocks before turning or stopping
+        let grid = Day17::parse("123\n456\n789").unwrap();
+        assert_eq!(Day17::part1(&grid), HeatLoss::Minimal(20));
+        assert_eq!(Day17::part2(&grid), HeatLoss::NoRoute);
+
+        let empty = Day17::parse("").unwrap();
+        assert_eq!(Day17::part1(&empty), HeatLoss::NoRoute);
+    }
+}
@@ -0,0 +1,81 @@
+//! Debug output of solutions.
+//!
+//! Solutions log through [`diag!`](crate::diag) instead of printing, so the output never mixes with
+//! the answers: by default messages go to stderr, but they can also be captured,
+//! e.g. to include them in machine-readable output.
+
+use std::cell::RefCell;
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Logs a diagnostic message, with the same arguments as `format!`.
+#[macro_export]
+macro_rules! diag {
+    ($($arg:tt)*) => {
+        $crate::diagnostics::emit(format!($($arg)*))
+    };
+}
+
+/// Logs `message`; prefer the [`diag!`](crate::diag) macro.
+pub fn emit(message: String) {
+    CAPTURED.with(|captured| match captured.borrow_mut().as_mut() {
+        Some(messages) => messages.push(message),
+        None => eprintln!("{message}"),
+    })
+}
+
+/// Puts back the capture that was active before [`capture`] started, even if `f` panics.
+struct RestoreOuter(Option<Vec<String>>);
+
+impl Drop for RestoreOuter {
+    fn drop(&mut self) {
+        let outer = self.0.take();
+        CAPTURED.with(|captured| captured.replace(outer));
+    }
+}
+
+/// Runs `f`, collecting all messages logged on this thread in the meantime instead of printing them.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    let _restore = RestoreOuter(CAPTURED.with(|captured| captured.replace(Some(vec![]))));
+    let result = f();
+    let messages = CAPTURED.with(|captured| captured.borrow_mut().take());
+    (result, messages.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn is_capturing() -> bool {
+        CAPTURED.with(|captured| captured.borrow().is_some())
+    }
+
+    #[test]
+    fn test_nested_capture() {
+        let ((inner, still_capturing), outer) = capture(|| {
+            emit("outer 1".to_owned());
+            let inner = capture(|| emit("inner".to_owned()));
+            emit("outer 2".to_owned());
+            (inner, is_capturing())
+        });
+
+        assert_eq!(inner, ((), vec!["inner".to_owned()]));
+        assert!(still_capturing);
+        assert_eq!(outer, ["outer 1", "outer 2"]);
+        assert!(!is_capturing());
+    }
+
+    #[test]
+    fn test_capture_restores_after_panic() {
+        let ((), outer) = capture(|| {
+            let result = std::panic::catch_unwind(|| capture(|| panic!("solution failed")));
+            assert!(result.is_err());
+            emit("after the panic".to_owned());
+        });
+
+        assert_eq!(outer, ["after the panic"]);
+        assert!(!is_capturing());
+    }
+}
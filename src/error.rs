@@ -0,0 +1,103 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Error for malformed puzzle input.
+///
+/// Lines and columns are stored zero based, but displayed one based to match editors.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    line: usize,
+    column: Option<usize>,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}", self.line + 1)?;
+        if let Some(column) = self.column {
+            write!(f, ", column {}", column + 1)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A single line of puzzle input, used to attach positions to parse errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    index: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(index: usize, text: &'a str) -> Self {
+        Self { index, text }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Error for the line as a whole.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.index, None, message)
+    }
+
+    /// Error pointing at `part`, which should be a slice of this line's text.
+    /// Falls back to an error for the whole line if it isn't.
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.index, self.column_of(part), message)
+    }
+
+    /// Error pointing at the given byte offset into the line.
+    pub fn error_at_column(&self, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.index, Some(column), message)
+    }
+
+    /// Parses `part` (a slice of this line's text), naming it `what` in the error message.
+    pub fn parse<T: FromStr>(&self, part: &str, what: &str) -> Result<T, ParseError> {
+        part.parse()
+            .map_err(|_| self.error_at(part, format!("invalid {what} `{part}`")))
+    }
+
+    fn column_of(&self, part: &str) -> Option<usize> {
+        let start = self.text.as_ptr() as usize;
+        let part_start = part.as_ptr() as usize;
+        (part_start >= start && part_start + part.len() <= start + self.text.len())
+            .then(|| part_start - start)
+    }
+}
+
+/// Iterates over the lines of `input`, keeping track of their indices.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| Line::new(index, text))
+}
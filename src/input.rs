@@ -0,0 +1,86 @@
+//! Loading puzzle input.
+
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+/// Where the input for a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// `-` means stdin, any other path is a file.
+    /// Without a path, the day's default input file is used.
+    pub fn resolve(day: u32, path: Option<&str>) -> Self {
+        match path {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(path.into()),
+            None => Source::default_for(day),
+        }
+    }
+
+    /// The file at [`default_path`].
+    pub fn default_for(day: u32) -> Self {
+        Source::File(default_path(day))
+    }
+
+    /// Reads the whole input and normalizes it with [`normalize`].
+    pub fn read(&self) -> io::Result<String> {
+        let text = match self {
+            Source::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                text
+            }
+            Source::File(path) => std::fs::read_to_string(path)?,
+        };
+        Ok(normalize(&text))
+    }
+}
+
+/// `input/{day}.txt`, relative to the working directory.
+pub fn default_path(day: u32) -> PathBuf {
+    format!("input/{day}.txt").into()
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Converts `\r\n` line endings to `\n` and removes trailing newlines,
+/// so solutions see the same text regardless of how the input was saved.
+pub fn normalize(text: &str) -> String {
+    text.replace("\r\n", "\n").trim_end_matches('\n').to_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a\r\nb\r\n\r\n"), "a\nb");
+        assert_eq!(normalize("a\n\nb\n"), "a\n\nb");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(Source::resolve(3, Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::resolve(3, Some("example.txt")),
+            Source::File("example.txt".into())
+        );
+        assert_eq!(Source::resolve(3, None), Source::File("input/3.txt".into()));
+    }
+}
@@ -0,0 +1,179 @@
+//! Maps on integers that shift whole intervals at once, like the almanac maps of day 5.
+
+use std::ops::Range;
+
+/// One interval of a [`RangeMap`]: values in `source` are shifted so that `source.start` maps to `dest`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Mapping {
+    source: Range<u64>,
+    dest: u64,
+}
+
+impl Mapping {
+    fn map(&self, value: u64) -> u64 {
+        self.dest + (value - self.source.start)
+    }
+}
+
+/// A piecewise offset map: every value inside one of the mapped intervals is shifted by that
+/// interval's offset, all other values map to themselves.
+///
+/// Intervals are kept sorted and non-overlapping, so lookups are binary searches.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    mappings: Vec<Mapping>,
+}
+
+impl RangeMap {
+    /// Maps the `len` values starting at `source` to the `len` values starting at `dest`.
+    pub fn insert(&mut self, dest: u64, source: u64, len: u64) -> Result<(), String> {
+        let (Some(source_end), Some(_)) = (source.checked_add(len), dest.checked_add(len)) else {
+            return Err("range exceeds the number range".to_owned());
+        };
+        if len == 0 {
+            return Ok(());
+        }
+
+        let idx = self.mappings.partition_point(|m| m.source.start < source);
+        let overlaps_previous = idx > 0 && self.mappings[idx - 1].source.end > source;
+        let overlaps_next =
+            idx < self.mappings.len() && self.mappings[idx].source.start < source_end;
+        if overlaps_previous || overlaps_next {
+            return Err(format!(
+                "source range {source}..{source_end} overlaps another range"
+            ));
+        }
+
+        self.mappings.insert(
+            idx,
+            Mapping {
+                source: source..source_end,
+                dest,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn get(&self, value: u64) -> u64 {
+        let idx = self.mappings.partition_point(|m| m.source.end <= value);
+        match self.mappings.get(idx) {
+            Some(mapping) if mapping.source.contains(&value) => mapping.map(value),
+            _ => value,
+        }
+    }
+
+    /// Splits `range` into the parts that are mapped as a whole,
+    /// returning each part together with what its start is mapped to.
+    fn split(&self, range: Range<u64>) -> Vec<(Range<u64>, u64)> {
+        let mut ret = vec![];
+
+        let mut start = range.start;
+        let first = self.mappings.partition_point(|m| m.source.end <= start);
+        for mapping in &self.mappings[first..] {
+            if start >= range.end || mapping.source.start >= range.end {
+                break;
+            }
+            if start < mapping.source.start {
+                // unmapped gap before this mapping
+                ret.push((start..mapping.source.start, start));
+                start = mapping.source.start;
+            }
+            let end = mapping.source.end.min(range.end);
+            ret.push((start..end, mapping.map(start)));
+            start = end;
+        }
+
+        if start < range.end {
+            ret.push((start..range.end, start));
+        }
+
+        ret
+    }
+
+    /// Maps every value of every range; the result can contain overlapping ranges.
+    pub fn map_ranges(&self, ranges: impl IntoIterator<Item = Range<u64>>) -> Vec<Range<u64>> {
+        ranges
+            .into_iter()
+            .flat_map(|range| self.split(range))
+            .map(|(range, dest)| dest..dest + (range.end - range.start))
+            .collect()
+    }
+
+    /// The map that first applies `self`, then `then`.
+    pub fn compose(&self, then: &RangeMap) -> RangeMap {
+        let mut ret = RangeMap::default();
+
+        // every part that `self` maps as a whole (including unmapped gaps)
+        // is split further depending on where `then` maps its image.
+        for (source, dest) in self.split(0..u64::MAX) {
+            let image = dest..dest + (source.end - source.start);
+            for (part, part_dest) in then.split(image) {
+                let part_source = source.start + (part.start - dest);
+                if part_source != part_dest {
+                    ret.mappings.push(Mapping {
+                        source: part_source..part_source + (part.end - part.start),
+                        dest: part_dest,
+                    });
+                }
+            }
+        }
+
+        ret
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn seed_to_soil() -> RangeMap {
+        let mut map = RangeMap::default();
+        map.insert(50, 98, 2).unwrap();
+        map.insert(52, 50, 48).unwrap();
+        map
+    }
+
+    fn soil_to_fertilizer() -> RangeMap {
+        let mut map = RangeMap::default();
+        map.insert(0, 15, 37).unwrap();
+        map.insert(37, 52, 2).unwrap();
+        map.insert(39, 0, 15).unwrap();
+        map
+    }
+
+    #[test]
+    fn test_get() {
+        let map = seed_to_soil();
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(14), 14);
+        assert_eq!(map.get(98), 50);
+        assert_eq!(map.get(100), 100);
+    }
+
+    #[test]
+    fn test_insert_overlap() {
+        let mut map = seed_to_soil();
+        assert!(map.insert(0, 90, 10).is_err());
+        assert!(map.insert(0, 40, 20).is_err());
+        assert!(map.insert(0, u64::MAX, 1).is_err());
+        assert!(map.insert(0, 40, 10).is_ok());
+    }
+
+    #[test]
+    fn test_map_ranges() {
+        let map = seed_to_soil();
+        let mut mapped = map.map_ranges([40..60, 95..105]);
+        mapped.sort_by_key(|r| r.start);
+        assert_eq!(mapped, vec![40..50, 50..52, 52..62, 97..100, 100..105]);
+    }
+
+    #[test]
+    fn test_compose() {
+        let first = seed_to_soil();
+        let second = soil_to_fertilizer();
+        let composed = first.compose(&second);
+        for value in 0..200 {
+            assert_eq!(composed.get(value), second.get(first.get(value)));
+        }
+    }
+}
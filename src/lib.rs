@@ -1 +1,12 @@
+pub mod bench;
+pub mod bigint;
+pub mod crt;
+pub mod cycle;
+pub mod days;
+pub mod diagnostics;
+pub mod error;
+pub mod input;
+pub mod interval;
+pub mod search;
+pub mod solution;
 pub mod util;
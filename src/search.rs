@@ -0,0 +1,271 @@
+//! Generic graph searches over user-defined states.
+//!
+//! States are discovered lazily through a `successors` closure, so the same functions work for
+//! grids, graphs and more abstract state spaces (e.g. position plus direction of movement).
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A path found by one of the searches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<S, C> {
+    /// Total cost of the path.
+    pub cost: C,
+    /// All states along the path, starting with the start state and ending with the goal.
+    pub path: Vec<S>,
+}
+
+/// Bookkeeping shared by all searches: every discovered state gets an id,
+/// so parents can be stored without cloning states around.
+struct Discovered<S> {
+    states: Vec<S>,
+    ids: HashMap<S, usize>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<S: Hash + Eq + Clone> Discovered<S> {
+    fn new() -> Self {
+        Self {
+            states: vec![],
+            ids: HashMap::new(),
+            parents: vec![],
+        }
+    }
+
+    /// Returns the id of `state` and whether it was newly discovered.
+    fn insert(&mut self, state: S, parent: Option<usize>) -> (usize, bool) {
+        match self.ids.entry(state) {
+            Entry::Occupied(e) => (*e.get(), false),
+            Entry::Vacant(e) => {
+                let id = self.states.len();
+                self.states.push(e.key().clone());
+                self.parents.push(parent);
+                e.insert(id);
+                (id, true)
+            }
+        }
+    }
+
+    fn path_to(&self, mut id: usize) -> Vec<S> {
+        let mut path = vec![self.states[id].clone()];
+        while let Some(parent) = self.parents[id] {
+            path.push(self.states[parent].clone());
+            id = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth first search; every step costs 1.
+/// Returns the shortest path from any of the `starts` to a state satisfying `is_goal`.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, usize>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut discovered = Discovered::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        let (id, new) = discovered.insert(start, None);
+        if new {
+            queue.push_back((id, 0));
+        }
+    }
+
+    while let Some((id, cost)) = queue.pop_front() {
+        if is_goal(&discovered.states[id]) {
+            return Some(SearchResult {
+                cost,
+                path: discovered.path_to(id),
+            });
+        }
+
+        for next in successors(&discovered.states[id]) {
+            let (next_id, new) = discovered.insert(next, Some(id));
+            if new {
+                queue.push_back((next_id, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// All states reachable from the `starts`, including the starts themselves (i.e. a flood fill).
+pub fn reachable<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> HashSet<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = HashSet::new();
+    let mut stack = vec![];
+    for start in starts {
+        if visited.insert(start.clone()) {
+            stack.push(start);
+        }
+    }
+
+    while let Some(state) = stack.pop() {
+        for next in successors(&state) {
+            if visited.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+
+    visited
+}
+
+/// Dijkstra's algorithm; `successors` yields the next states together with the cost to reach them.
+/// Costs must not be negative. `C::default()` is used as zero.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, C>>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. Like [`dijkstra`], but states are explored in order of their cost plus the
+/// `heuristic` estimate of the remaining cost. The result is only guaranteed to be optimal if
+/// the heuristic never overestimates and is consistent.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, C>>
+where
+    S: Hash + Eq + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut discovered = Discovered::new();
+    let mut costs: Vec<C> = vec![];
+    let mut closed: Vec<bool> = vec![];
+
+    // Reverse to make it a min heap.
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let (id, new) = discovered.insert(start, None);
+        if new {
+            costs.push(C::default());
+            closed.push(false);
+            let estimate = heuristic(&discovered.states[id]);
+            queue.push(Reverse((estimate, C::default(), id)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, id))) = queue.pop() {
+        if closed[id] || cost > costs[id] {
+            // outdated queue entry
+            continue;
+        }
+        closed[id] = true;
+
+        if is_goal(&discovered.states[id]) {
+            return Some(SearchResult {
+                cost,
+                path: discovered.path_to(id),
+            });
+        }
+
+        for (next, step_cost) in successors(&discovered.states[id]) {
+            let next_cost = cost + step_cost;
+            let (next_id, new) = discovered.insert(next, Some(id));
+            if new {
+                costs.push(next_cost);
+                closed.push(false);
+            } else if closed[next_id] || next_cost >= costs[next_id] {
+                continue;
+            } else {
+                costs[next_id] = next_cost;
+                discovered.parents[next_id] = Some(id);
+            }
+
+            let estimate = next_cost + heuristic(&discovered.states[next_id]);
+            queue.push(Reverse((estimate, next_cost, next_id)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 2 -1-> 3
+    // 0 ------5-----------> 3
+    // 0 -2-> 4 -2-> 3
+    fn weighted_successors(n: &u32) -> Vec<(u32, u32)> {
+        match n {
+            0 => vec![(1, 1), (3, 5), (4, 2)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            4 => vec![(3, 2)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs(
+            [0],
+            |n| weighted_successors(n).into_iter().map(|(n, _)| n),
+            |n| *n == 3,
+        );
+        assert_eq!(
+            result,
+            Some(SearchResult {
+                cost: 1,
+                path: vec![0, 3]
+            })
+        );
+        assert_eq!(bfs([0], |_| [], |n| *n == 3), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = dijkstra([0], weighted_successors, |n| *n == 3).unwrap();
+        assert_eq!(result.cost, 3);
+        assert_eq!(result.path, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_astar() {
+        // number line, walking costs 1 per step; the heuristic is the exact distance
+        let result = astar(
+            [0i32],
+            |n| [(n - 1, 1), (n + 1, 1)],
+            |n| (10 - n).abs(),
+            |n| *n == 10,
+        )
+        .unwrap();
+        assert_eq!(result.cost, 10);
+        assert_eq!(result.path, (0..=10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_reachable() {
+        let visited = reachable([0], |n| weighted_successors(n).into_iter().map(|(n, _)| n));
+        assert_eq!(visited, HashSet::from([0, 1, 2, 3, 4]));
+    }
+}
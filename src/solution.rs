@@ -0,0 +1,91 @@
+use std::{any::Any, fmt::Display};
+
+use crate::error::ParseError;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// How [`Solution::render`] may draw its output.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Style {
+    Plain,
+    /// Text with ANSI escape codes for colors.
+    Ansi,
+}
+
+/// A puzzle solution for a single day.
+///
+/// The input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+
+    /// Optional visualization of how `part` is solved, e.g. the input with the solution drawn on top.
+    fn render(_input: &Self::Input, _part: Part, _style: Style) -> Option<String> {
+        None
+    }
+}
+
+/// Object safe version of [`Solution`] so solutions for different days can be stored side by side.
+/// Implemented for every [`Solution`].
+pub trait DynSolution: Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Solves `part` for input previously returned by [`DynSolution::parse`] of the same solution.
+    fn solve(&self, input: &dyn Any, part: Part) -> String;
+
+    /// See [`Solution::render`].
+    fn render(&self, input: &dyn Any, part: Part, style: Style) -> Option<String>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        let input = <S as Solution>::parse(input)?;
+        Ok(Box::new(input))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> String {
+        let input = downcast_input::<S>(input);
+        match part {
+            Part::One => S::part1(input).to_string(),
+            Part::Two => S::part2(input).to_string(),
+        }
+    }
+
+    fn render(&self, input: &dyn Any, part: Part, style: Style) -> Option<String> {
+        S::render(downcast_input::<S>(input), part, style)
+    }
+}
+
+fn downcast_input<S>(input: &dyn Any) -> &S::Input
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .expect("input was parsed by a different solution")
+}
@@ -1,4 +1,10 @@
-use std::ops::{Add, AddAssign, Index, IndexMut, Mul};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    ops::{Add, AddAssign, Index, IndexMut, Mul},
+};
+
+use crate::error::{self, ParseError};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
 pub struct Index2d {
@@ -44,6 +50,13 @@ pub enum Direction {
 }
 
 impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+    ];
+
     pub fn to_index(self) -> Index2d {
         match self {
             Direction::Up => Index2d { x: 0, y: -1 },
@@ -53,6 +66,26 @@ impl Direction {
         }
     }
 
+    /// Direction of a step from one cell to an adjacent one.
+    pub fn from_index(offset: Index2d) -> Option<Direction> {
+        match (offset.x, offset.y) {
+            (0, -1) => Some(Direction::Up),
+            (-1, 0) => Some(Direction::Left),
+            (0, 1) => Some(Direction::Down),
+            (1, 0) => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    pub fn to_arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Left => '<',
+            Direction::Down => 'v',
+            Direction::Right => '>',
+        }
+    }
+
     pub fn invert(self) -> Direction {
         match self {
             Direction::Up => Direction::Down,
@@ -82,6 +115,40 @@ impl<T> Grid2d<T> {
         }
     }
 
+    /// Builds a grid from lines of text, mapping each character with `f`.
+    /// All lines need to have the same number of characters.
+    pub fn parse<E: Display>(
+        text: &str,
+        f: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut len_x = None;
+        let mut len_y = 0;
+        let mut data = vec![];
+
+        for line in error::lines(text) {
+            let mut row_len = 0;
+            for (column, c) in line.text().char_indices() {
+                data.push(f(c).map_err(|e| line.error_at_column(column, e.to_string()))?);
+                row_len += 1;
+            }
+
+            let expected_len = *len_x.get_or_insert(row_len);
+            if row_len != expected_len {
+                return Err(line.error_at_column(
+                    row_len.min(expected_len),
+                    format!("expected a row of length {expected_len}, found length {row_len}"),
+                ));
+            }
+            len_y += 1;
+        }
+
+        Ok(Self {
+            len_x: len_x.unwrap_or(0),
+            len_y,
+            data,
+        })
+    }
+
     pub fn is_valid(&self, index: Index2d) -> bool {
         index.x >= 0
             && (index.x as usize) < self.len_x
@@ -100,6 +167,148 @@ impl<T> Grid2d<T> {
     pub fn len_y(&self) -> usize {
         self.len_y
     }
+
+    /// All valid indices of the grid, row by row.
+    pub fn indices(&self) -> impl Iterator<Item = Index2d> {
+        let len_x = self.len_x;
+        (0..self.len_y).flat_map(move |y| {
+            (0..len_x).map(move |x| Index2d {
+                x: x as i32,
+                y: y as i32,
+            })
+        })
+    }
+
+    /// Iterates over all cells together with their index, row by row.
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (Index2d, &T)> {
+        self.indices().zip(self.data.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` because `chunks` doesn't accept a chunk size of 0; an empty grid has no data anyway.
+        self.data.chunks(self.len_x.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.len_x).map(move |x| self.data.iter().skip(x).step_by(self.len_x))
+    }
+
+    /// The horizontally and vertically adjacent indices that lie inside the grid.
+    pub fn neighbors4(&self, index: Index2d) -> impl Iterator<Item = Index2d> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| index + direction.to_index())
+            .filter(|neighbor| self.is_valid(*neighbor))
+    }
+
+    /// Like [`Grid2d::neighbors4`], but also includes diagonally adjacent indices.
+    pub fn neighbors8(&self, index: Index2d) -> impl Iterator<Item = Index2d> + '_ {
+        (-1..=1)
+            .flat_map(|y| (-1..=1).map(move |x| Index2d { x, y }))
+            .filter(|offset| *offset != Index2d { x: 0, y: 0 })
+            .map(move |offset| index + offset)
+            .filter(|neighbor| self.is_valid(*neighbor))
+    }
+}
+
+impl<T> Grid2d<T> {
+    /// Renders the grid as text, one line per row, mapping each cell with `f`.
+    pub fn render(&self, f: impl Fn(&T) -> char) -> String {
+        self.render_with_overlay(f, &Overlay::default())
+    }
+
+    /// Like [`Grid2d::render`], but cells covered by the overlay are drawn with the overlay's character.
+    pub fn render_with_overlay(&self, f: impl Fn(&T) -> char, overlay: &Overlay) -> String {
+        let mut s = String::with_capacity((self.len_x + 1) * self.len_y);
+        for (index, cell) in self.iter_indexed() {
+            if index.x == 0 && index.y > 0 {
+                s.push('\n');
+            }
+            s.push(overlay.get(index).unwrap_or_else(|| f(cell)));
+        }
+        s
+    }
+}
+
+/// Cell types that can be displayed as a single character.
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+impl<T: ToChar> Display for Grid2d<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(T::to_char))
+    }
+}
+
+/// Cells that link up with some of their neighbors, like pipes.
+pub trait Connects {
+    /// Whether the cell leads to its neighbor in `direction`.
+    fn connects(&self, direction: Direction) -> bool;
+}
+
+impl<T: Connects> Grid2d<T> {
+    /// Whether the cell at `index` and its neighbor in `direction` both lead to each other.
+    pub fn is_linked(&self, index: Index2d, direction: Direction) -> bool {
+        let neighbor = index + direction.to_index();
+        self.is_valid(index)
+            && self.is_valid(neighbor)
+            && self[index].connects(direction)
+            && self[neighbor].connects(direction.invert())
+    }
+
+    /// The directions in which the cell at `index` is linked to its neighbor.
+    pub fn linked_directions(&self, index: Index2d) -> impl Iterator<Item = Direction> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter(move |direction| self.is_linked(index, *direction))
+    }
+}
+
+/// Characters drawn over a grid when rendering it, e.g. to highlight cells or show a path.
+#[derive(Debug, Clone, Default)]
+pub struct Overlay {
+    cells: HashMap<Index2d, char>,
+}
+
+impl Overlay {
+    pub fn get(&self, index: Index2d) -> Option<char> {
+        self.cells.get(&index).copied()
+    }
+
+    pub fn set(&mut self, index: Index2d, c: char) -> &mut Self {
+        self.cells.insert(index, c);
+        self
+    }
+
+    pub fn highlight(&mut self, cells: impl IntoIterator<Item = Index2d>, c: char) -> &mut Self {
+        for index in cells {
+            self.set(index, c);
+        }
+        self
+    }
+
+    /// Draws arrows along a path of straight segments, pointing in the direction of movement.
+    /// Consecutive points have to be in the same row or column.
+    pub fn path(&mut self, points: &[Index2d]) -> &mut Self {
+        for segment in points.windows(2) {
+            let (mut current, end) = (segment[0], segment[1]);
+            let step = Index2d {
+                x: (end.x - current.x).signum(),
+                y: (end.y - current.y).signum(),
+            };
+            let Some(direction) = Direction::from_index(step) else {
+                // no movement or not a straight line
+                continue;
+            };
+
+            while current != end {
+                current += step;
+                self.set(current, direction.to_arrow());
+            }
+        }
+        self
+    }
 }
 
 impl<T> Index<Index2d> for Grid2d<T> {
@@ -117,3 +326,130 @@ impl<T> IndexMut<Index2d> for Grid2d<T> {
         &mut self.data[idx]
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse_digits(text: &str) -> Result<Grid2d<u32>, ParseError> {
+        Grid2d::parse(text, |c| c.to_digit(10).ok_or("not a digit"))
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let grid = parse_digits("123\n456\n").unwrap();
+        assert_eq!(grid.len_x(), 3);
+        assert_eq!(grid.len_y(), 2);
+        assert_eq!(grid[Index2d { x: 2, y: 1 }], 6);
+    }
+
+    #[test]
+    fn test_parse_grid_errors() {
+        let err = parse_digits("123\n4x6").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, Some(1)));
+
+        let err = parse_digits("123\n45\n789").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, Some(2)));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = parse_digits("123\n456").unwrap();
+        let rows = grid.rows().map(<[u32]>::to_vec).collect::<Vec<_>>();
+        assert_eq!(rows, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        let columns = grid
+            .columns()
+            .map(|c| c.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = parse_digits("123\n456\n789").unwrap();
+        let values = |neighbors: Vec<Index2d>| {
+            let mut values = neighbors.into_iter().map(|n| grid[n]).collect::<Vec<_>>();
+            values.sort();
+            values
+        };
+
+        let corner = Index2d { x: 0, y: 0 };
+        assert_eq!(values(grid.neighbors4(corner).collect()), vec![2, 4]);
+        assert_eq!(values(grid.neighbors8(corner).collect()), vec![2, 4, 5]);
+
+        let center = Index2d { x: 1, y: 1 };
+        assert_eq!(values(grid.neighbors4(center).collect()), vec![2, 4, 6, 8]);
+        assert_eq!(
+            values(grid.neighbors8(center).collect()),
+            vec![1, 2, 3, 4, 6, 7, 8, 9]
+        );
+    }
+
+    impl ToChar for u32 {
+        fn to_char(&self) -> char {
+            char::from_digit(*self, 10).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = parse_digits("123\n456\n789").unwrap();
+        assert_eq!(
+            grid.render(|n| char::from_digit(*n, 10).unwrap()),
+            "123\n456\n789"
+        );
+        assert_eq!(grid.to_string(), "123\n456\n789");
+
+        let mut overlay = Overlay::default();
+        overlay.highlight([Index2d { x: 0, y: 0 }, Index2d { x: 2, y: 2 }], '#');
+        assert_eq!(
+            grid.render_with_overlay(u32::to_char, &overlay),
+            "#23\n456\n78#"
+        );
+    }
+
+    #[test]
+    fn test_overlay_path() {
+        let grid = parse_digits("123\n456\n789").unwrap();
+        let render_path = |points: &[Index2d]| {
+            let mut overlay = Overlay::default();
+            overlay.path(points);
+            grid.render_with_overlay(u32::to_char, &overlay)
+        };
+
+        // the start isn't drawn, every later cell shows the direction it was entered in
+        let turn = [
+            Index2d { x: 0, y: 0 },
+            Index2d { x: 2, y: 0 },
+            Index2d { x: 2, y: 2 },
+            Index2d { x: 1, y: 2 },
+        ];
+        assert_eq!(render_path(&turn), "1>>\n45v\n7<v");
+
+        // diagonal steps and steps without movement are skipped
+        let skipped = [
+            Index2d { x: 0, y: 0 },
+            Index2d { x: 1, y: 1 },
+            Index2d { x: 1, y: 1 },
+            Index2d { x: 1, y: 0 },
+        ];
+        assert_eq!(render_path(&skipped), "1^3\n456\n789");
+    }
+
+    /// `true` cells lead everywhere, `false` cells nowhere.
+    impl Connects for bool {
+        fn connects(&self, _direction: Direction) -> bool {
+            *self
+        }
+    }
+
+    #[test]
+    fn test_linked_directions() {
+        let grid = Grid2d::parse("##.\n.#.", |c| Ok::<_, String>(c == '#')).unwrap();
+        let linked = |x, y| grid.linked_directions(Index2d { x, y }).collect::<Vec<_>>();
+        assert_eq!(linked(1, 0), [Direction::Left, Direction::Down]);
+        assert_eq!(linked(0, 0), [Direction::Right]);
+        assert_eq!(linked(2, 0), []);
+    }
+}
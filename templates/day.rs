@@ -0,0 +1,47 @@
+use crate::{
+    error::{self, ParseError},
+    solution::Solution,
+};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        error::lines(input)
+            .map(|line| Ok(line.text().to_owned()))
+            .collect()
+    }
+
+    fn part1(_input: &Self::Input) -> u64 {
+        todo!()
+    }
+
+    fn part2(_input: &Self::Input) -> u64 {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn test_part1_example() {
+        let input = Day{{day}}::parse(EXAMPLE).unwrap();
+        assert_eq!(Day{{day}}::part1(&input), 0);
+    }
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn test_part2_example() {
+        let input = Day{{day}}::parse(EXAMPLE).unwrap();
+        assert_eq!(Day{{day}}::part2(&input), 0);
+    }
+}
@@ -0,0 +1,123 @@
+//! Runs every registered day against the example inputs in `tests/examples`
+//! and compares the results with the expected answers listed in `tests/examples/answers.txt`.
+//! An answer of `?` marks an example that hasn't been filled in yet, e.g. for a day just created with
+//! `aoc new`; it counts as covered but isn't checked.
+
+use std::{
+    collections::BTreeSet,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+use aoc2023::{days, input::Source, solution::Part};
+
+struct Expectation {
+    file: String,
+    day: u32,
+    part: Part,
+    answer: String,
+}
+
+fn examples_dir() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/examples"))
+}
+
+fn read_expectations() -> Vec<Expectation> {
+    let answers = std::fs::read_to_string(examples_dir().join("answers.txt"))
+        .expect("can't read answers.txt");
+
+    answers
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let [file, part, answer] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+                panic!("expected `<file> <part> <answer>`, got `{line}`");
+            };
+            // file names start with `dayNN`, optionally followed by a suffix for multiple examples
+            let day = file
+                .strip_prefix("day")
+                .and_then(|rest| rest.get(..2))
+                .and_then(|day| day.parse().ok())
+                .unwrap_or_else(|| panic!("can't get the day from file name `{file}`"));
+            let part = match part {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => panic!("invalid part `{part}` in line `{line}`"),
+            };
+            Expectation {
+                file: file.to_owned(),
+                day,
+                part,
+                answer: answer.to_owned(),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn examples_match_expected_answers() {
+    let mut mismatches = vec![];
+
+    for expectation in read_expectations() {
+        let Expectation {
+            file,
+            day,
+            part,
+            answer,
+        } = expectation;
+        if answer == "?" {
+            continue;
+        }
+        let what = format!("day {day} part {part} ({file})");
+
+        let Some(solution) = days::get(day) else {
+            mismatches.push(format!("{what}: no solution registered"));
+            continue;
+        };
+        let input = Source::File(examples_dir().join(&file))
+            .read()
+            .unwrap_or_else(|e| panic!("can't read example `{file}`: {e}"));
+        let input = match solution.parse(&input) {
+            Ok(input) => input,
+            Err(e) => {
+                mismatches.push(format!("{what}: parse error: {e}"));
+                continue;
+            }
+        };
+
+        // keep going after a panic so all broken days show up in the report
+        let actual = panic::catch_unwind(AssertUnwindSafe(|| solution.solve(input.as_ref(), part)));
+        match actual {
+            Ok(actual) if actual == answer => {}
+            Ok(actual) => mismatches.push(format!("{what}: expected {answer}, got {actual}")),
+            Err(_) => mismatches.push(format!("{what}: panicked")),
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "wrong answers for examples:\n{}",
+        mismatches.join("\n")
+    );
+}
+
+#[test]
+fn every_day_has_examples() {
+    let covered = read_expectations()
+        .iter()
+        .map(|e| (e.day, e.part))
+        .collect::<BTreeSet<_>>();
+
+    let missing = days::REGISTRY
+        .iter()
+        .flat_map(|(day, _)| [(*day, Part::One), (*day, Part::Two)])
+        .filter(|key| !covered.contains(key))
+        .map(|(day, part)| format!("day {day} part {part}"))
+        .collect::<Vec<_>>();
+
+    assert!(
+        missing.is_empty(),
+        "no expected answers for: {}",
+        missing.join(", ")
+    );
+}